@@ -1,10 +1,64 @@
+mod case_conversion;
 mod util;
 
-use darling::{FromDeriveInput, FromField};
+use bytesize::ByteSize;
+use case_conversion::RenameRule;
+use darling::util::Flag;
+use darling::{FromDeriveInput, FromField, FromMeta, FromVariant};
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use proc_macro_error::{abort, proc_macro_error};
 use quote::quote;
-use util::{matches_option_signature, matches_vec_signature};
+use syn::parse_quote;
+use util::{extract_inner_type, matches_option_signature, matches_vec_signature, uses_type_params};
+
+/// Strategy used when a non-list field is supplied more than once.
+#[derive(Debug, Default, Clone, Copy)]
+enum DuplicatePolicy {
+    /// Keep the first occurrence of the field.
+    First,
+    /// Keep the last occurrence of the field.
+    Last,
+    /// Return an error.
+    #[default]
+    Reject,
+}
+
+impl FromMeta for DuplicatePolicy {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "first" => Ok(Self::First),
+            "last" => Ok(Self::Last),
+            "reject" => Ok(Self::Reject),
+            _ => Err(darling::Error::unknown_value(value)),
+        }
+    }
+}
+
+/// Notation accepted for the names of the fields of a nested struct.
+#[derive(Debug, Clone, Copy)]
+enum NestedNotation {
+    /// Accept both the dot and the bracket notation.
+    Any,
+    /// Accept only the `parent.field` notation.
+    Dot,
+    /// Accept only the `parent[field]` notation.
+    Bracket,
+}
+
+impl FromMeta for NestedNotation {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::Any)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "dot" => Ok(Self::Dot),
+            "bracket" => Ok(Self::Bracket),
+            _ => Err(darling::Error::unknown_value(value)),
+        }
+    }
+}
 
 #[derive(Debug, FromField)]
 #[darling(attributes(form_data))]
@@ -12,26 +66,68 @@ struct FieldData {
     ident: Option<syn::Ident>,
     ty: syn::Type,
     field_name: Option<String>,
-    #[darling(default)]
-    default: bool,
+    limit: Option<String>,
+    default: Flag,
+    duplicate: Option<DuplicatePolicy>,
+    nested: Option<NestedNotation>,
+    #[darling(multiple)]
+    alias: Vec<String>,
+    #[darling(multiple)]
+    content_type: Vec<String>,
+    max_items: Option<usize>,
+    min_items: Option<usize>,
+    format: Option<String>,
+    from_str: Flag,
+    checkbox: Flag,
 }
 
 impl FieldData {
+    /// Whether a missing field should be populated using the type's [Default]
+    /// implementation, which is implied for checkboxes.
+    fn has_default(&self) -> bool {
+        self.default.is_present() || self.checkbox.is_present()
+    }
+
     /// Get the name of the field from the `field_name` attribute, falling back
-    /// to the field identifier.
-    fn name(&self) -> String {
+    /// to the field identifier converted using the `rename_all` rule, if any.
+    fn name(&self, rename_all: Option<RenameRule>) -> String {
         if let Some(field_name) = &self.field_name {
             return field_name.to_string();
         }
 
         let ident = self.ident.as_ref().unwrap().to_string();
 
-        if ident.starts_with("r#") {
-            // If the field is using a raw identifier we want to strip the
-            // leading characters.
-            ident.chars().skip(2).collect()
-        } else {
-            ident
+        // If the field is using a raw identifier we want to strip the leading
+        // characters.
+        let ident = ident.strip_prefix("r#").unwrap_or(&ident);
+
+        match rename_all {
+            Some(rule) => rule.apply_to_field(ident),
+            None => ident.to_string(),
+        }
+    }
+
+    /// Get the type of the values produced for the field, i.e. the inner type
+    /// for [Option] and [Vec] fields.
+    fn value_ty(&self) -> &syn::Type {
+        match matches_vec_signature(&self.ty) || matches_option_signature(&self.ty) {
+            true => extract_inner_type(&self.ty).unwrap_or(&self.ty),
+            false => &self.ty,
+        }
+    }
+
+    /// Parse the human readable `limit` attribute into the maximum number of
+    /// bytes allowed for the field, where [None] means unlimited.
+    fn limit_bytes(&self) -> Option<usize> {
+        match self.limit.as_deref() {
+            None | Some("unlimited") => None,
+            Some(limit) => match limit.parse::<ByteSize>() {
+                Ok(size) => Some(size.as_u64() as usize),
+                Err(_) => abort!(
+                    self.ident,
+                    "limit must be a valid size (e.g. \"10MiB\") or \"unlimited\""
+                ),
+            },
         }
     }
 }
@@ -40,7 +136,15 @@ impl FieldData {
 #[darling(attributes(form_data), supports(struct_named))]
 struct InputData {
     ident: syn::Ident,
+    vis: syn::Visibility,
+    generics: syn::Generics,
     data: darling::ast::Data<(), FieldData>,
+    strict: Flag,
+    duplicate: Option<DuplicatePolicy>,
+    skip_nameless: Flag,
+    collect_errors: Flag,
+    rename_all: Option<RenameRule>,
+    max_fields: Option<usize>,
 }
 
 /// Derive the `TryFromMultipart` trait for arbitrary named structs.
@@ -49,76 +153,805 @@ struct InputData {
 pub fn try_from_multipart_derive(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
 
-    let InputData { ident, data } = match InputData::from_derive_input(&input) {
+    let InputData {
+        ident,
+        vis,
+        mut generics,
+        data,
+        strict,
+        duplicate,
+        skip_nameless,
+        collect_errors,
+        rename_all,
+        max_fields,
+    } = match InputData::from_derive_input(&input) {
         Ok(input) => input,
         Err(err) => abort!(input, err.to_string()),
     };
 
     let fields = data.take_struct().unwrap();
 
-    let declarations = fields.iter().map(|FieldData { ident, ty, default, .. }| {
-         if matches_vec_signature(ty) {
-            quote! { let mut #ident: #ty = std::vec::Vec::new(); }
-        } else if matches_option_signature(ty) {
-            quote! { let mut #ident: #ty = std::option::Option::None; }
-        } else if *default {
-            quote! { let mut #ident: std::option::Option<#ty> = std::option::Option::Some(#ty::default()); }
+    for field @ FieldData { ident, ty, max_items, min_items, format, nested, from_str, .. } in
+        fields.iter()
+    {
+        if (max_items.is_some() || min_items.is_some()) && !matches_vec_signature(ty) {
+            abort!(ident, "max_items and min_items can only be used on list fields");
+        }
+
+        if format.is_some() && nested.is_some() {
+            abort!(ident, "format cannot be used on nested fields");
+        }
+
+        // The nested fields are parsed by the nested struct, so the parameters
+        // applied to the field data must be set on its own fields instead.
+        if nested.is_some() && !field.content_type.is_empty() {
+            abort!(ident, "content_type cannot be used on nested fields");
+        }
+
+        if nested.is_some() && field.limit.is_some() {
+            abort!(ident, "limit cannot be used on nested fields");
+        }
+
+        if nested.is_some() && field.duplicate.is_some() {
+            abort!(ident, "duplicate cannot be used on nested fields");
+        }
+
+        if from_str.is_present() && (format.is_some() || nested.is_some()) {
+            abort!(ident, "from_str cannot be used together with format or nested");
+        }
+
+        // Missing checkboxes are unchecked, so wrapping the field in an [Option]
+        // or a [Vec] would not be meaningful.
+        if field.checkbox.is_present() {
+            if quote!(#ty).to_string() != "bool" {
+                abort!(ident, "checkbox can only be used on bool fields");
+            }
+
+            if from_str.is_present() || format.is_some() || nested.is_some() {
+                abort!(ident, "checkbox cannot be used together with from_str, format or nested");
+            }
+        }
+    }
+
+    // Require the fields that depend on the type parameters of the struct to
+    // implement the traits needed by the generated code.
+    let type_params = generics.type_params().map(|param| param.ident.clone()).collect::<Vec<_>>();
+    let type_params = type_params.iter().collect::<Vec<_>>();
+
+    for field @ FieldData { ty, default, nested, format, from_str, .. } in fields.iter() {
+        if !uses_type_params(ty, &type_params) {
+            continue;
+        }
+
+        let value_ty = field.value_ty();
+        let predicates = &mut generics.make_where_clause().predicates;
+
+        if nested.is_some() {
+            predicates
+                .push(parse_quote! { #value_ty: axum_typed_multipart::TryFromNestedMultipart });
+        } else if format.is_some() {
+            predicates.push(
+                parse_quote! { #value_ty: axum_typed_multipart::TryFromFieldWithFormat + Send },
+            );
+        } else if from_str.is_present() {
+            predicates.push(parse_quote! { #value_ty: std::str::FromStr + Send });
+            predicates.push(parse_quote! {
+                <#value_ty as std::str::FromStr>::Err: Into<Box<dyn std::error::Error + Send + Sync>>
+            });
+        } else {
+            predicates.push(parse_quote! { #value_ty: axum_typed_multipart::TryFromField + Send });
+        }
+
+        if default.is_present() {
+            predicates.push(parse_quote! { #ty: Default });
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let collect_errors = collect_errors.is_present();
+
+    // Abort the parsing of the current field with the supplied error, or add
+    // it to the list of errors to be returned at the end if we are collecting.
+    let report = |field_name: TokenStream2, error: TokenStream2| {
+        if collect_errors {
+            quote! {
+                __state__.__errors__.push(axum_typed_multipart::FieldError {
+                    field_name: #field_name,
+                    error: #error,
+                });
+                return Ok(None);
+            }
         } else {
-            quote! { let mut #ident: std::option::Option<#ty> = std::option::Option::None; }
+            quote! { return Err(#error); }
         }
+    };
+
+    // Handle the `error` returned while parsing the current field: when
+    // collecting, the errors related to a specific field are recorded while
+    // the others (e.g. a malformed request body) still abort the parsing.
+    let on_error = if collect_errors {
+        quote! {
+            match error.get_field_name().map(String::from) {
+                Some(field_name) => {
+                    __state__.__errors__.push(axum_typed_multipart::FieldError { field_name, error });
+                    return Ok(None);
+                }
+                None => return Err(error),
+            }
+        }
+    } else {
+        quote! { return Err(error); }
+    };
+
+    let state_fields = fields.iter().map(|field @ FieldData { ident, ty, nested, .. }| {
+        let value_ty = field.value_ty();
+
+        // The notation of the first field received for a nested struct is
+        // stored along with its state, so errors can be reported using the
+        // same notation as the request.
+        let state_ty = if nested.is_none() {
+            if matches_vec_signature(ty) || matches_option_signature(ty) {
+                quote! { #ty }
+            } else {
+                quote! { std::option::Option<#ty> }
+            }
+        } else if matches_vec_signature(ty) {
+            quote! {
+                std::collections::BTreeMap<
+                    usize,
+                    (
+                        axum_typed_multipart::Notation,
+                        axum_typed_multipart::Notation,
+                        <#value_ty as axum_typed_multipart::TryFromNestedMultipart>::State,
+                    )
+                >
+            }
+        } else {
+            quote! {
+                std::option::Option<(
+                    axum_typed_multipart::Notation,
+                    <#value_ty as axum_typed_multipart::TryFromNestedMultipart>::State,
+                )>
+            }
+        };
+
+        quote! { #ident: #state_ty }
     });
 
-    let assignments = fields.iter().map(|field @ FieldData { ident, ty, .. }| {
-        let name = field.name();
+    let consumers = fields.iter().map(|field @ FieldData { ident, ty, nested, alias, content_type, .. }| {
+        let name = field.name(rename_all);
+
+        // Aliases are matched in place of the name, so they are treated as
+        // the same field when detecting duplicates.
+        let pattern = quote! { #name #(| #alias)* };
+
+        let Some(notation) = nested else {
+            let limit_bytes = match field.limit_bytes() {
+                Some(limit_bytes) => quote! { std::option::Option::Some(#limit_bytes) },
+                None => quote! { std::option::Option::None },
+            };
+
+            let value_ty = field.value_ty();
+            let parse = match &field.format {
+                Some(format) => quote! {
+                    <#value_ty as axum_typed_multipart::TryFromFieldWithFormat>::try_from_field_with_format(
+                        __field__,
+                        #limit_bytes,
+                        #format,
+                    ).await
+                },
+                None if field.checkbox.is_present() => quote! {
+                    <axum_typed_multipart::Checkbox as axum_typed_multipart::TryFromField>::try_from_field(
+                        __field__,
+                        #limit_bytes,
+                    ).await.map(bool::from)
+                },
+                None if field.from_str.is_present() => quote! {
+                    <axum_typed_multipart::FromStrField<#value_ty> as axum_typed_multipart::TryFromField>::try_from_field(
+                        __field__,
+                        #limit_bytes,
+                    ).await.map(|value| value.0)
+                },
+                None => quote! {
+                    <#value_ty as axum_typed_multipart::TryFromField>::try_from_field(__field__, #limit_bytes).await
+                },
+            };
+
+            // The errors are reported using the canonical name of the field,
+            // even when it was supplied using an alias.
+            let value = quote! {
+                match #parse {
+                    Ok(value) => value,
+                    Err(error) => {
+                        let error = error.with_field_name(__path__.join(#name));
+                        #on_error
+                    }
+                }
+            };
+
+            let assignment = if matches_vec_signature(ty) {
+                let check = field.max_items.map(|limit| {
+                    let too_many = report(
+                        quote! { __path__.join(#name) },
+                        quote! {
+                            axum_typed_multipart::TypedMultipartError::TooManyItems {
+                                field_name: __path__.join(#name),
+                                limit: #limit,
+                            }
+                        },
+                    );
+
+                    quote! {
+                        if __state__.#ident.len() >= #limit {
+                            #too_many
+                        }
+                    }
+                });
+
+                quote! {
+                    #check
+                    __state__.#ident.push(#value);
+                }
+            } else if field.checkbox.is_present() && field.duplicate.is_none() {
+                // Checkboxes are commonly preceded by a hidden input with the
+                // same name supplying the unchecked value, so the field is
+                // checked if any of the values is.
+                quote! {
+                    let value = #value;
+                    __state__.#ident = Some(__state__.#ident.unwrap_or(false) || value);
+                }
+            } else {
+                match field.duplicate.or(duplicate).unwrap_or_default() {
+                    DuplicatePolicy::First => quote! {
+                        if __state__.#ident.is_none() {
+                            __state__.#ident = Some(#value);
+                        }
+                    },
+                    DuplicatePolicy::Last => quote! { __state__.#ident = Some(#value); },
+                    DuplicatePolicy::Reject => {
+                        let duplicate = report(
+                            quote! { __path__.join(#name) },
+                            quote! {
+                                axum_typed_multipart::TypedMultipartError::DuplicateField {
+                                    field_name: __path__.join(#name)
+                                }
+                            },
+                        );
+
+                        quote! {
+                            if __state__.#ident.is_some() {
+                                #duplicate
+                            }
+                            __state__.#ident = Some(#value);
+                        }
+                    }
+                }
+            };
+
+            let content_type_check = if content_type.is_empty() {
+                quote! {}
+            } else {
+                let unsupported = report(
+                    quote! { __path__.join(#name) },
+                    quote! {
+                        axum_typed_multipart::TypedMultipartError::UnsupportedContentType {
+                            field_name: __path__.join(#name),
+                            got: __field__.content_type().map(String::from),
+                            allowed: vec![#(String::from(#content_type)),*],
+                        }
+                    },
+                );
+
+                quote! {
+                    if !axum_typed_multipart::matches_content_type(
+                        __field__.content_type(),
+                        &[#(#content_type),*],
+                    ) {
+                        #unsupported
+                    }
+                }
+            };
+
+            return quote! {
+                if matches!(__name__, #pattern) {
+                    #content_type_check
+                    #assignment
+                    return Ok(None);
+                }
+            };
+        };
+
+        let value_ty = field.value_ty();
 
-        let value = quote! {
-            axum_typed_multipart::TryFromField::try_from_field(__field__).await?
+        let filter = match notation {
+            NestedNotation::Any => quote! { |(head, _, _)| matches!(*head, #pattern) },
+            NestedNotation::Dot => quote! {
+                |(head, notation, _)| matches!(*head, #pattern)
+                    && *notation == axum_typed_multipart::Notation::Dot
+            },
+            NestedNotation::Bracket => quote! {
+                |(head, notation, _)| matches!(*head, #pattern)
+                    && *notation == axum_typed_multipart::Notation::Bracket
+            },
         };
 
-        let assignment = if matches_vec_signature(ty) {
-            quote! { #ident.push(#value); }
+        let consume = |state: TokenStream2, name: TokenStream2, path: TokenStream2| {
+            quote! {
+                match <#value_ty as axum_typed_multipart::TryFromNestedMultipart>::consume_field(
+                    #state, __field__, #name, #path
+                ).await {
+                    Ok(None) => return Ok(None),
+                    Ok(Some(field)) => __field__ = field,
+                    Err(error) => { #on_error }
+                }
+            }
+        };
+
+        let consumer = if matches_vec_signature(ty) {
+            let check = field.max_items.map(|limit| {
+                let too_many = report(
+                    quote! { __path__.join(#name) },
+                    quote! {
+                        axum_typed_multipart::TypedMultipartError::TooManyItems {
+                            field_name: __path__.join(#name),
+                            limit: #limit,
+                        }
+                    },
+                );
+
+                quote! {
+                    if __state__.#ident.len() >= #limit && !__state__.#ident.contains_key(&position) {
+                        #too_many
+                    }
+                }
+            });
+
+            // List items are identified by their index, e.g. `items[0][name]`.
+            let consumer = consume(
+                quote! {
+                    &mut __state__.#ident
+                        .entry(position)
+                        .or_insert_with(|| (*notation, item_notation, Default::default()))
+                        .2
+                },
+                quote! { &item_rest },
+                quote! { axum_typed_multipart::FieldPath::Nested(&prefix, item_notation) },
+            );
+
+            quote! {
+                #check
+                #consumer
+            }
         } else {
-            quote! { #ident = Some(#value); }
+            consume(
+                quote! {
+                    &mut __state__.#ident.get_or_insert_with(|| (*notation, Default::default())).1
+                },
+                quote! { rest },
+                quote! { axum_typed_multipart::FieldPath::Nested(&__path__.join(#name), *notation) },
+            )
         };
 
+        if matches_vec_signature(ty) {
+            quote! {
+                if let Some((_, notation, rest)) = __nested__.as_ref().filter(#filter) {
+                    if let Some((index, item_notation, item_rest)) = axum_typed_multipart::Notation::split(rest) {
+                        if let Ok(position) = index.parse::<usize>() {
+                            let prefix = axum_typed_multipart::FieldPath::Nested(&__path__.join(#name), *notation).join(index);
+                            #consumer
+                        }
+                    }
+                }
+            }
+        } else {
+            quote! {
+                if let Some((_, notation, rest)) = __nested__.as_ref().filter(#filter) {
+                    #consumer
+                }
+            }
+        }
+    });
+
+    let has_nested = fields.iter().any(|FieldData { nested, .. }| nested.is_some());
+
+    let nested_split = if has_nested {
         quote! {
-            if __field__name__ == #name {
-                #assignment
+            let mut __field__ = __field__;
+            let __nested__ = axum_typed_multipart::Notation::split(__name__);
+        }
+    } else {
+        quote! {}
+    };
+
+    let fallback = if strict.is_present() {
+        report(
+            quote! { String::from(__field__.name().unwrap_or(__name__)) },
+            quote! {
+                axum_typed_multipart::TypedMultipartError::UnknownField {
+                    field_name: String::from(__field__.name().unwrap_or(__name__))
+                }
+            },
+        )
+    } else {
+        quote! { Ok(Some(__field__)) }
+    };
+
+    // Record the `error` returned while creating a nested struct.
+    let record = if collect_errors {
+        quote! {
+            match error {
+                axum_typed_multipart::TypedMultipartError::Multiple(errors) => __errors__.extend(errors),
+                error => match error.get_field_name().map(String::from) {
+                    Some(field_name) => {
+                        __errors__.push(axum_typed_multipart::FieldError { field_name, error });
+                    }
+                    None => return Err(error),
+                },
             }
         }
+    } else {
+        quote! { return Err(error); }
+    };
+
+    let nested_fields = fields.iter().filter_map(|field @ FieldData { ident, ty, nested, .. }| {
+        let notation = match (*nested)? {
+            NestedNotation::Any | NestedNotation::Dot => quote! { axum_typed_multipart::Notation::Dot },
+            NestedNotation::Bracket => quote! { axum_typed_multipart::Notation::Bracket },
+        };
+
+        let name = field.name(rename_all);
+        let value_ty = field.value_ty();
+
+        let from_state = quote! {
+            <#value_ty as axum_typed_multipart::TryFromNestedMultipart>::from_state
+        };
+
+        let output = if matches_vec_signature(ty) {
+            quote! {
+                let #ident = {
+                    let mut __values__ = std::vec::Vec::new();
+
+                    for (index, (notation, item_notation, item_state)) in #ident {
+                        let prefix = axum_typed_multipart::FieldPath::Nested(&__path__.join(#name), notation)
+                            .join(&index.to_string());
+                        let item_path = axum_typed_multipart::FieldPath::Nested(&prefix, item_notation);
+
+                        match #from_state(item_state, item_path) {
+                            Ok(value) => __values__.push(value),
+                            Err(error) => { #record }
+                        }
+                    }
+
+                    __values__
+                };
+            }
+        } else {
+            let path = quote! {
+                axum_typed_multipart::FieldPath::Nested(&__path__.join(#name), notation)
+            };
+
+            let result = if matches_option_signature(ty) {
+                quote! {
+                    #ident.map(|(notation, nested_state)| #from_state(nested_state, #path))
+                }
+            } else {
+                quote! {{
+                    let (notation, nested_state) = #ident
+                        .unwrap_or_else(|| (#notation, Default::default()));
+                    #from_state(nested_state, #path)
+                }}
+            };
+
+            match (collect_errors, matches_option_signature(ty)) {
+                (false, false) => quote! { let #ident = #result?; },
+                (false, true) => quote! { let #ident = #result.transpose()?; },
+                // Required nested structs are unwrapped together with the
+                // other required fields once all the errors are collected.
+                (true, false) => quote! {
+                    let #ident = match #result {
+                        Ok(value) => Some(value),
+                        Err(error) => { #record; None }
+                    };
+                },
+                (true, true) => quote! {
+                    let #ident = match #result {
+                        Some(Ok(value)) => Some(value),
+                        Some(Err(error)) => { #record; None }
+                        None => None,
+                    };
+                },
+            }
+        };
+
+        Some(output)
     });
 
     let required_fields = fields
         .iter()
-        .filter(|FieldData { ty, .. }| !matches_option_signature(ty) && !matches_vec_signature(ty));
+        .filter(|FieldData { ty, .. }| !matches_option_signature(ty) && !matches_vec_signature(ty))
+        .collect::<Vec<_>>();
 
-    let checks = required_fields.map(|field @ FieldData { ident, .. }| {
-        let field_name = field.name();
-        quote! {
-            let #ident = #ident.ok_or(
-                axum_typed_multipart::TypedMultipartError::MissingField {
-                    field_name: String::from(#field_name)
+    let length_checks =
+        fields.iter().filter_map(|field @ FieldData { ident, min_items, .. }| {
+            let limit = (*min_items)?;
+            let field_name = field.name(rename_all);
+
+            let error = quote! {
+                axum_typed_multipart::TypedMultipartError::TooFewItems {
+                    field_name: __path__.join(#field_name),
+                    limit: #limit,
+                }
+            };
+
+            let fail = if collect_errors {
+                quote! {
+                    __errors__.push(axum_typed_multipart::FieldError {
+                        field_name: __path__.join(#field_name),
+                        error: #error,
+                    });
                 }
-            )?;
+            } else {
+                quote! { return Err(#error); }
+            };
+
+            Some(quote! {
+                if #ident.len() < #limit {
+                    #fail
+                }
+            })
+        });
+
+    let checks = if collect_errors {
+        let missing = required_fields
+            .iter()
+            .filter(|field| !field.has_default() && field.nested.is_none())
+            .map(|field @ FieldData { ident, .. }| {
+                let field_name = field.name(rename_all);
+
+                // Fields that were supplied with an invalid value are already
+                // reported, so we don't want to mark them as missing.
+                quote! {
+                    if #ident.is_none() && !__errors__.iter().any(|e| e.field_name == __path__.join(#field_name)) {
+                        __errors__.push(axum_typed_multipart::FieldError {
+                            field_name: __path__.join(#field_name),
+                            error: axum_typed_multipart::TypedMultipartError::MissingField {
+                                field_name: __path__.join(#field_name)
+                            },
+                        });
+                    }
+                }
+            });
+
+        let unwraps = required_fields.iter().map(|field @ FieldData { ident, .. }| {
+            if field.has_default() {
+                quote! { let #ident = #ident.unwrap_or_default(); }
+            } else {
+                quote! { let #ident = #ident.unwrap(); }
+            }
+        });
+
+        quote! {
+            #(#missing)*
+
+            if !__errors__.is_empty() {
+                return Err(axum_typed_multipart::TypedMultipartError::Multiple(__errors__));
+            }
+
+            #(#unwraps)*
         }
-    });
+    } else {
+        let checks = required_fields
+            .iter()
+            .filter(|FieldData { nested, .. }| nested.is_none())
+            .map(|field @ FieldData { ident, .. }| {
+                let field_name = field.name(rename_all);
+
+                if field.has_default() {
+                    return quote! { let #ident = #ident.unwrap_or_default(); };
+                }
+
+                quote! {
+                    let #ident = #ident.ok_or(
+                        axum_typed_multipart::TypedMultipartError::MissingField {
+                            field_name: __path__.join(#field_name)
+                        }
+                    )?;
+                }
+            });
+
+        quote! { #(#checks)* }
+    };
+
+    let (errors_field, errors_default, errors_binding) = if collect_errors {
+        (
+            quote! { __errors__: std::vec::Vec<axum_typed_multipart::FieldError>, },
+            quote! { __errors__: std::vec::Vec::new(), },
+            quote! { mut __errors__, },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! {})
+    };
+
+    let (fields_counter, fields_check) = match max_fields {
+        Some(limit) => (
+            quote! { let mut __fields__ = 0usize; },
+            quote! {
+                __fields__ += 1;
+
+                if __fields__ > #limit {
+                    return Err(axum_typed_multipart::TypedMultipartError::TooManyFields {
+                        limit: #limit
+                    });
+                }
+            },
+        ),
+        None => (quote! {}, quote! {}),
+    };
+
+    let nameless = if skip_nameless.is_present() {
+        quote! { continue; }
+    } else {
+        quote! { return Err(axum_typed_multipart::TypedMultipartError::NamelessField); }
+    };
 
-    let idents = fields.iter().map(|FieldData { ident, .. }| ident);
+    let idents = fields.iter().map(|FieldData { ident, .. }| ident).collect::<Vec<_>>();
 
     let output = quote! {
-        #[axum::async_trait]
-        impl axum_typed_multipart::TryFromMultipart for #ident {
-            async fn try_from_multipart(multipart: &mut axum::extract::Multipart) -> Result<Self, axum_typed_multipart::TypedMultipartError> {
-                #(#declarations)*
+        const _: () = {
+            #vis struct __State #impl_generics #where_clause {
+                #(#state_fields,)*
+                #errors_field
+                __marker__: std::marker::PhantomData<fn() -> #ident #ty_generics>,
+            }
+
+            impl #impl_generics Default for __State #ty_generics #where_clause {
+                fn default() -> Self {
+                    Self {
+                        #(#idents: Default::default(),)*
+                        #errors_default
+                        __marker__: std::marker::PhantomData,
+                    }
+                }
+            }
+
+            #[axum::async_trait]
+            impl #impl_generics axum_typed_multipart::TryFromNestedMultipart for #ident #ty_generics #where_clause {
+                type State = __State #ty_generics;
+
+                async fn consume_field<'__field>(
+                    __state__: &mut Self::State,
+                    __field__: axum::extract::multipart::Field<'__field>,
+                    __name__: &str,
+                    __path__: axum_typed_multipart::FieldPath<'_>,
+                ) -> Result<Option<axum::extract::multipart::Field<'__field>>, axum_typed_multipart::TypedMultipartError> {
+                    #nested_split
+                    #(#consumers)*
+                    #fallback
+                }
+
+                fn from_state(
+                    __state__: Self::State,
+                    __path__: axum_typed_multipart::FieldPath<'_>,
+                ) -> Result<Self, axum_typed_multipart::TypedMultipartError> {
+                    let __State { #(#idents,)* #errors_binding .. } = __state__;
+
+                    #(#nested_fields)*
+
+                    #(#length_checks)*
 
-                while let Some(__field__) = multipart.next_field().await? {
-                    let __field__name__ = __field__.name().unwrap().to_string();
-                    #(#assignments) else *
+                    #checks
+
+                    Ok(Self { #(#idents),* })
                 }
+            }
+
+            #[axum::async_trait]
+            impl #impl_generics axum_typed_multipart::TryFromMultipart for #ident #ty_generics #where_clause {
+                async fn try_from_multipart(multipart: &mut axum::extract::Multipart) -> Result<Self, axum_typed_multipart::TypedMultipartError> {
+                    let mut state = <Self as axum_typed_multipart::TryFromNestedMultipart>::State::default();
+
+                    #fields_counter
+
+                    while let Some(field) = multipart.next_field().await? {
+                        #fields_check
+
+                        let name = match field.name() {
+                            Some(name) => name.to_string(),
+                            None => { #nameless }
+                        };
+
+                        <Self as axum_typed_multipart::TryFromNestedMultipart>::consume_field(
+                            &mut state, field, &name, axum_typed_multipart::FieldPath::Root
+                        ).await?;
+                    }
+
+                    <Self as axum_typed_multipart::TryFromNestedMultipart>::from_state(
+                        state, axum_typed_multipart::FieldPath::Root
+                    )
+                }
+            }
+        };
+    };
+
+    output.into()
+}
+
+#[derive(Debug, FromVariant)]
+#[darling(attributes(form_data))]
+struct VariantData {
+    ident: syn::Ident,
+    rename: Option<String>,
+}
+
+impl VariantData {
+    /// Get the name of the variant from the `rename` attribute, falling back
+    /// to the identifier converted using the `rename_all` rule, if any.
+    fn name(&self, rename_all: Option<RenameRule>) -> String {
+        if let Some(rename) = &self.rename {
+            return rename.to_string();
+        }
+
+        let ident = self.ident.to_string();
+
+        match rename_all {
+            Some(rule) => rule.apply_to_variant(&ident),
+            None => ident,
+        }
+    }
+}
 
-                #(#checks)*
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(form_data), supports(enum_unit))]
+struct EnumData {
+    ident: syn::Ident,
+    data: darling::ast::Data<VariantData, ()>,
+    rename_all: Option<RenameRule>,
+}
 
-                Ok(Self { #(#idents),* })
+/// Derive the `TryFromField` trait for enums with only unit variants.
+#[proc_macro_error]
+#[proc_macro_derive(TryFromField, attributes(form_data))]
+pub fn try_from_field_derive(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    let EnumData { ident, data, rename_all } = match EnumData::from_derive_input(&input) {
+        Ok(input) => input,
+        Err(err) => abort!(input, err.to_string()),
+    };
+
+    let variants = data.take_enum().unwrap();
+    let names = variants.iter().map(|variant| variant.name(rename_all)).collect::<Vec<_>>();
+    let idents = variants.iter().map(|VariantData { ident, .. }| ident);
+
+    // The allowed values are reported in place of the type name so the client
+    // knows what to supply, e.g. `red | green | blue`.
+    let wanted_type = names.join(" | ");
+
+    let output = quote! {
+        #[axum::async_trait]
+        impl axum_typed_multipart::TryFromField for #ident {
+            async fn try_from_field(
+                field: axum::extract::multipart::Field<'_>,
+                limit_bytes: Option<usize>,
+            ) -> Result<Self, axum_typed_multipart::TypedMultipartError> {
+                let field_name = field
+                    .name()
+                    .map(String::from)
+                    .ok_or(axum_typed_multipart::TypedMultipartError::NamelessField)?;
+
+                let text = <String as axum_typed_multipart::TryFromField>::try_from_field(
+                    field, limit_bytes
+                ).await?;
+
+                match text.as_str() {
+                    #(#names => Ok(Self::#idents),)*
+                    _ => Err(axum_typed_multipart::TypedMultipartError::WrongFieldType {
+                        field_name,
+                        wanted_type: String::from(#wanted_type),
+                        source: None,
+                    }),
+                }
             }
         }
     };
@@ -25,3 +25,34 @@ pub fn matches_option_signature(ty: &syn::Type) -> bool {
 pub fn matches_vec_signature(ty: &syn::Type) -> bool {
     matches_signature(ty, &["Vec", "std::vec::Vec"])
 }
+
+/// Get the first generic type argument of the supplied type, e.g. `T` for
+/// `Vec<T>`.
+pub fn extract_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let path = match ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+        _ => return None,
+    };
+
+    match &path.segments.last()?.arguments {
+        syn::PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// Check if the supplied type references at least one of the provided type
+/// parameters.
+pub fn uses_type_params(ty: &syn::Type, params: &[&syn::Ident]) -> bool {
+    fn visit(tokens: proc_macro2::TokenStream, params: &[&syn::Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => params.contains(&&ident),
+            proc_macro2::TokenTree::Group(group) => visit(group.stream(), params),
+            _ => false,
+        })
+    }
+
+    visit(quote::quote!(#ty), params)
+}
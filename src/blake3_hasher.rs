@@ -0,0 +1,35 @@
+use digest::consts::U32;
+use digest::{FixedOutput, HashMarker, Output, OutputSizeUser, Reset, Update};
+
+/// BLAKE3 hasher implementing the [Digest](digest::Digest) trait, to be used
+/// with the [Hashed](crate::Hashed) wrapper.
+///
+/// The [blake3] crate only implements the traits of the latest version of the
+/// [digest] crate, so this type adapts its hasher to the version used by the
+/// other algorithms.
+#[derive(Clone, Default, Debug)]
+pub struct Blake3(blake3::Hasher);
+
+impl HashMarker for Blake3 {}
+
+impl OutputSizeUser for Blake3 {
+    type OutputSize = U32;
+}
+
+impl Update for Blake3 {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+}
+
+impl FixedOutput for Blake3 {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(self.0.finalize().as_bytes());
+    }
+}
+
+impl Reset for Blake3 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
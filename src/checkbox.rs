@@ -0,0 +1,53 @@
+use crate::{FieldMetadata, TryFromChunks, TypedMultipartError};
+use axum::async_trait;
+use axum::body::Bytes;
+use futures_util::stream::Stream;
+
+/// Values parsed as an unchecked checkbox, compared case-insensitively.
+const UNCHECKED: [&str; 4] = ["off", "0", "no", "false"];
+
+/// Boolean parsed leniently from the value of an HTML checkbox.
+///
+/// Browsers submit `on`, or the `value` attribute of the input, for checked
+/// checkboxes, so any value is parsed as checked except for `off`, `0`, `no`
+/// and `false` (ignoring the case), which are parsed as unchecked.
+///
+/// Since browsers do not submit unchecked checkboxes, the field should be
+/// marked with the `default` parameter of the `form_data` attribute.
+/// Alternatively the `checkbox` parameter can be used on [bool] fields to
+/// apply the same parsing and treat a missing field as `false`.
+///
+/// ## Example
+///
+/// ```rust
+/// use axum_typed_multipart::{Checkbox, TryFromMultipart};
+///
+/// #[derive(TryFromMultipart)]
+/// struct RequestData {
+///     #[form_data(default)]
+///     newsletter: Checkbox,
+///     #[form_data(checkbox)]
+///     terms: bool,
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Checkbox(pub bool);
+
+impl From<Checkbox> for bool {
+    fn from(checkbox: Checkbox) -> Self {
+        checkbox.0
+    }
+}
+
+#[async_trait]
+impl TryFromChunks for Checkbox {
+    async fn try_from_chunks(
+        chunks: impl Stream<Item = Result<Bytes, TypedMultipartError>> + Send + Unpin,
+        metadata: FieldMetadata,
+    ) -> Result<Self, TypedMultipartError> {
+        let text = String::try_from_chunks(chunks, metadata).await?;
+        let text = text.trim();
+
+        Ok(Self(!UNCHECKED.iter().any(|value| text.eq_ignore_ascii_case(value))))
+    }
+}
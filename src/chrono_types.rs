@@ -0,0 +1,57 @@
+use crate::try_from_field_with_format::{gen_date_time_impl, parse_any};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+/// Formats produced by the HTML `date` input.
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Formats produced by the HTML `time` input, with optional seconds.
+const TIME_FORMATS: [&str; 2] = ["%H:%M:%S%.f", "%H:%M"];
+
+/// Formats produced by the HTML `datetime-local` input, with optional seconds.
+const DATE_TIME_FORMATS: [&str; 2] = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M"];
+
+/// Make sure the custom `format` only contains supported specifiers.
+fn check_format(format: &str) -> Result<&str, &'static str> {
+    match StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        true => Err("unsupported specifier"),
+        false => Ok(format),
+    }
+}
+
+gen_date_time_impl!(
+    chrono::NaiveDate,
+    |text| NaiveDate::parse_from_str(text, DATE_FORMAT),
+    |format| check_format(format),
+    |text, format| NaiveDate::parse_from_str(text, format),
+);
+
+gen_date_time_impl!(
+    chrono::NaiveTime,
+    |text| parse_any(&TIME_FORMATS, |format| NaiveTime::parse_from_str(text, format)),
+    |format| check_format(format),
+    |text, format| NaiveTime::parse_from_str(text, format),
+);
+
+gen_date_time_impl!(
+    chrono::NaiveDateTime,
+    |text| parse_any(&DATE_TIME_FORMATS, |format| NaiveDateTime::parse_from_str(text, format)),
+    |format| check_format(format),
+    |text, format| NaiveDateTime::parse_from_str(text, format),
+);
+
+gen_date_time_impl!(
+    chrono::DateTime<chrono::FixedOffset>,
+    |text| DateTime::parse_from_rfc3339(text),
+    |format| check_format(format),
+    |text, format| DateTime::<FixedOffset>::parse_from_str(text, format),
+);
+
+gen_date_time_impl!(
+    chrono::DateTime<chrono::Utc>,
+    |text| DateTime::parse_from_rfc3339(text).map(|date_time| date_time.with_timezone(&Utc)),
+    |format| check_format(format),
+    |text, format| {
+        DateTime::parse_from_str(text, format).map(|date_time| date_time.with_timezone(&Utc))
+    },
+);
@@ -0,0 +1,30 @@
+/// Check if the supplied `content_type` matches at least one of the `allowed`
+/// patterns.
+///
+/// The patterns can be either full MIME types (e.g. `image/png`), wildcards on
+/// the subtype (e.g. `image/*`) or `*/*` to allow any type. The parameters of
+/// the content type are ignored and a missing content type is treated as
+/// `text/plain`, which is the default for multipart fields.
+///
+/// ## Example
+///
+/// ```rust
+/// use axum_typed_multipart::matches_content_type;
+///
+/// assert!(matches_content_type(Some("image/png"), &["image/*"]));
+/// assert!(matches_content_type(None, &["text/plain"]));
+/// assert!(!matches_content_type(Some("text/html; charset=utf-8"), &["image/*"]));
+/// ```
+pub fn matches_content_type(content_type: Option<&str>, allowed: &[&str]) -> bool {
+    let content_type = content_type.unwrap_or("text/plain");
+    let essence = content_type.split(';').next().unwrap_or_default().trim().to_lowercase();
+    let (type_, _) = essence.split_once('/').unwrap_or((&essence, ""));
+
+    allowed.iter().map(|pattern| pattern.trim().to_lowercase()).any(|pattern| {
+        match pattern.strip_suffix("/*") {
+            Some("*") => true,
+            Some(pattern) => pattern == type_,
+            None => pattern == essence,
+        }
+    })
+}
@@ -0,0 +1,81 @@
+/// Notation used in the form data to express the name of a nested field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    /// Nested fields are named using the `parent.field` notation.
+    Dot,
+    /// Nested fields are named using the `parent[field]` notation.
+    Bracket,
+}
+
+impl Notation {
+    /// Split the name of a nested field into the name of the parent, the
+    /// notation used and the name of the field relative to the parent.
+    ///
+    /// Returns [None] if the name does not contain a nested field.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use axum_typed_multipart::Notation;
+    ///
+    /// let split = Notation::split("items[0][name]");
+    /// assert_eq!(split, Some(("items", Notation::Bracket, String::from("0[name]"))));
+    ///
+    /// let split = Notation::split("address.street");
+    /// assert_eq!(split, Some(("address", Notation::Dot, String::from("street"))));
+    /// ```
+    pub fn split(name: &str) -> Option<(&str, Notation, String)> {
+        let index = name.find(['.', '['])?;
+        let (head, tail) = name.split_at(index);
+
+        if head.is_empty() {
+            return None;
+        }
+
+        if let Some(rest) = tail.strip_prefix('.') {
+            return match rest.is_empty() {
+                true => None,
+                false => Some((head, Notation::Dot, rest.to_string())),
+            };
+        }
+
+        let tail = &tail[1..];
+        let end = tail.find(']')?;
+        let (inner, remainder) = (&tail[..end], &tail[end + 1..]);
+
+        match inner.is_empty() {
+            true => None,
+            false => Some((head, Notation::Bracket, format!("{inner}{remainder}"))),
+        }
+    }
+}
+
+/// Location of a struct inside the form data, used to build the full name of
+/// its fields.
+#[derive(Debug, Clone, Copy)]
+pub enum FieldPath<'a> {
+    /// The struct is not nested.
+    Root,
+    /// The struct is nested under the supplied prefix.
+    Nested(&'a str, Notation),
+}
+
+impl FieldPath<'_> {
+    /// Get the full name of the field with the supplied name.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use axum_typed_multipart::{FieldPath, Notation};
+    ///
+    /// let path = FieldPath::Nested("address", Notation::Bracket);
+    /// assert_eq!(path.join("street"), "address[street]");
+    /// ```
+    pub fn join(&self, name: &str) -> String {
+        match self {
+            Self::Root => name.to_string(),
+            Self::Nested(prefix, Notation::Dot) => format!("{prefix}.{name}"),
+            Self::Nested(prefix, Notation::Bracket) => format!("{prefix}[{name}]"),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::short_type_name::short_type_name;
+use crate::{FieldMetadata, TryFromChunks, TypedMultipartError};
+use axum::async_trait;
+use axum::body::Bytes;
+use futures_util::stream::Stream;
+use std::any::type_name;
+use std::error::Error;
+use std::str::FromStr;
+
+/// Wrapper parsing the field data using the [FromStr] implementation of the
+/// inner type, which removes the need to implement
+/// [TryFromField](crate::TryFromField) for custom scalar types.
+///
+/// When parsing fails the [FromStr::Err] is preserved as the `source` of the
+/// returned [WrongFieldType](crate::TypedMultipartError::WrongFieldType)
+/// error.
+///
+/// The `from_str` parameter of the `form_data` attribute can be used to apply
+/// the same logic without wrapping the type of the field.
+///
+/// ## Example
+///
+/// ```rust
+/// use axum_typed_multipart::{FromStrField, TryFromMultipart};
+/// use std::str::FromStr;
+///
+/// struct Sku(String);
+///
+/// impl FromStr for Sku {
+///     type Err = &'static str;
+///
+///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+///         match s.starts_with("SKU-") {
+///             true => Ok(Sku(s.to_string())),
+///             false => Err("missing SKU- prefix"),
+///         }
+///     }
+/// }
+///
+/// #[derive(TryFromMultipart)]
+/// struct RequestData {
+///     sku: FromStrField<Sku>,
+///     #[form_data(from_str)]
+///     replacement: Option<Sku>,
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FromStrField<T>(pub T);
+
+#[async_trait]
+impl<T> TryFromChunks for FromStrField<T>
+where
+    T: FromStr + Send,
+    T::Err: Into<Box<dyn Error + Send + Sync>>,
+{
+    async fn try_from_chunks(
+        chunks: impl Stream<Item = Result<Bytes, TypedMultipartError>> + Send + Unpin,
+        metadata: FieldMetadata,
+    ) -> Result<Self, TypedMultipartError> {
+        let field_name = metadata.name.clone().ok_or(TypedMultipartError::NamelessField)?;
+        let text = String::try_from_chunks(chunks, metadata).await?;
+
+        match T::from_str(&text) {
+            Ok(value) => Ok(Self(value)),
+            Err(error) => Err(TypedMultipartError::WrongFieldType {
+                field_name,
+                wanted_type: short_type_name(type_name::<T>()),
+                source: Some(error.into()),
+            }),
+        }
+    }
+}
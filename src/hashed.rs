@@ -0,0 +1,64 @@
+use crate::{FieldMetadata, TryFromChunks, TypedMultipartError};
+use axum::async_trait;
+use axum::body::Bytes;
+use digest::{Digest, Output};
+use futures_util::stream::{Stream, StreamExt};
+
+/// Wrapper computing the digest of the field data while it is being received,
+/// without needing to read the contents back once the field is parsed.
+///
+/// The digest algorithm `D` can be any type implementing the [Digest] trait.
+/// The SHA-256 (`sha2` feature), SHA-1 (`sha1` feature), MD5 (`md5` feature)
+/// and BLAKE3 (`blake3` feature) algorithms are re-exported by this crate.
+///
+/// Multiple digests can be computed at once by nesting the wrapper, e.g.
+/// `Hashed<Hashed<TempFile, Sha256>, Md5>`.
+///
+/// ## Example
+///
+#[cfg_attr(feature = "sha2", doc = "```rust")]
+#[cfg_attr(not(feature = "sha2"), doc = "```rust,ignore")]
+/// use axum_typed_multipart::{Hashed, Sha256, TempFile, TryFromMultipart};
+///
+/// #[derive(TryFromMultipart)]
+/// struct FileUpload {
+///     file: Hashed<TempFile, Sha256>,
+/// }
+/// ```
+pub struct Hashed<T, D: Digest> {
+    pub contents: T,
+    pub digest: Output<D>,
+}
+
+impl<T, D: Digest> Hashed<T, D> {
+    /// Get the digest encoded as a lowercase hexadecimal string.
+    pub fn hex_digest(&self) -> String {
+        self.digest.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
+
+#[async_trait]
+impl<T, D> TryFromChunks for Hashed<T, D>
+where
+    T: TryFromChunks + Send,
+    D: Digest + Send,
+{
+    async fn try_from_chunks(
+        chunks: impl Stream<Item = Result<Bytes, TypedMultipartError>> + Send + Unpin,
+        metadata: FieldMetadata,
+    ) -> Result<Self, TypedMultipartError> {
+        let mut hasher = D::new();
+
+        let chunks = chunks.map(|chunk| {
+            if let Ok(chunk) = &chunk {
+                hasher.update(chunk);
+            }
+
+            chunk
+        });
+
+        let contents = T::try_from_chunks(chunks, metadata).await?;
+
+        Ok(Self { contents, digest: hasher.finalize() })
+    }
+}
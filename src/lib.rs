@@ -24,6 +24,11 @@
 //! [String], and [Bytes](axum::body::Bytes), in case you want to access the
 //! raw data.
 //!
+//! The trait is also implemented for the `NonZero*` integers, the IP and socket
+//! addresses of [std::net] and [PathBuf](std::path::PathBuf), while the `uuid`,
+//! `url`, `rust_decimal` and `semver` features add support for the types of
+//! the corresponding crates.
+//!
 //! If the request body is malformed or it does not contain the required data
 //! the request will be aborted with an error.
 //!
@@ -76,6 +81,35 @@
 //! }
 //! ```
 //!
+//! The same case conventions supported by `serde` can be applied to all the
+//! fields at once using the `rename_all` parameter on the struct, while
+//! `field_name` can still be used to override the name of a single field.
+//!
+//! ```rust
+//! use axum_typed_multipart::TryFromMultipart;
+//!
+//! #[derive(TryFromMultipart)]
+//! #[form_data(rename_all = "camelCase")]
+//! struct RequestData {
+//!     first_name: String, // Read from the `firstName` field.
+//!     #[form_data(field_name = "surname")]
+//!     last_name: String,
+//! }
+//! ```
+//!
+//! The `alias` parameter, which can be repeated, allows accepting additional
+//! names for a field, e.g. the previous name of a renamed field.
+//!
+//! ```rust
+//! use axum_typed_multipart::TryFromMultipart;
+//!
+//! #[derive(TryFromMultipart)]
+//! struct RequestData {
+//!     #[form_data(alias = "username", alias = "login")]
+//!     name: String,
+//! }
+//! ```
+//!
 //! ### Default values
 //!
 //! If the `default` parameter in the `form_data` attribute is present the value
@@ -93,6 +127,113 @@
 //! }
 //! ```
 //!
+//! ### Strict mode
+//!
+//! By default fields that do not match any of the struct fields are ignored.
+//! If the `strict` parameter is supplied to the `form_data` attribute on the
+//! struct the request will be aborted with an error instead, which is useful to
+//! catch typos in the field names.
+//!
+//! ```rust
+//! use axum_typed_multipart::TryFromMultipart;
+//!
+//! #[derive(TryFromMultipart)]
+//! #[form_data(strict)]
+//! struct RequestData {
+//!     name: String,
+//! }
+//! ```
+//!
+//! ### Duplicate fields
+//!
+//! By default the request will be aborted with an error if a field that is
+//! not declared as a list is supplied more than once. This behaviour can be
+//! changed using the `duplicate` parameter of the `form_data` attribute, either
+//! on a single field or on the whole struct, allowing to keep the `"first"` or
+//! the `"last"` occurrence of the field.
+//!
+//! ```rust
+//! use axum_typed_multipart::TryFromMultipart;
+//!
+//! #[derive(TryFromMultipart)]
+//! #[form_data(duplicate = "last")]
+//! struct RequestData {
+//!     name: String,
+//!     #[form_data(duplicate = "first")]
+//!     email: String,
+//! }
+//! ```
+//!
+//! ### Collecting errors
+//!
+//! By default the request will be aborted as soon as the first invalid field
+//! is encountered. If the `collect_errors` parameter is supplied to the
+//! `form_data` attribute on the struct all the missing and invalid fields will
+//! be reported at once using the
+//! [Multiple](crate::TypedMultipartError::Multiple) error variant, which is
+//! useful to display the errors next to each input in a form.
+//!
+//! ```rust
+//! use axum_typed_multipart::TryFromMultipart;
+//!
+//! #[derive(TryFromMultipart)]
+//! #[form_data(collect_errors)]
+//! struct RequestData {
+//!     name: String,
+//!     age: u8,
+//! }
+//! ```
+//!
+//! ### Field size limits
+//!
+//! The size of each field can be limited using the `limit` parameter of the
+//! `form_data` attribute, which accepts human readable units (e.g. `"10MiB"`).
+//! The request will be aborted with a `413 Payload Too Large` status code if
+//! the field contents exceed the specified size.
+//!
+//! ```rust
+//! use axum::body::Bytes;
+//! use axum_typed_multipart::TryFromMultipart;
+//!
+//! #[derive(TryFromMultipart)]
+//! struct RequestData {
+//!     #[form_data(limit = "1KiB")]
+//!     name: String,
+//!     #[form_data(limit = "10MiB")]
+//!     image: Bytes,
+//! }
+//! ```
+//!
+//! The number of items of the list fields can be limited using the `max_items`
+//! and `min_items` parameters, while the `max_fields` parameter on the struct
+//! limits the total number of fields in the request.
+//!
+//! The total size of the request, the number of parts and the size of the
+//! headers of each part can be limited by adding a
+//! [TypedMultipartConfig](crate::TypedMultipartConfig) extension to the router.
+//!
+//! ### Content types
+//!
+//! The `content_type` parameter of the `form_data` attribute can be used to
+//! restrict the content types accepted for a field, returning a
+//! `415 Unsupported Media Type` error for the other fields. The parameter can
+//! be repeated and supports wildcards.
+//!
+//! ```rust
+//! use axum_typed_multipart::{TempFile, TryFromMultipart};
+//!
+//! #[derive(TryFromMultipart)]
+//! struct RequestData {
+//!     #[form_data(content_type = "image/*", content_type = "application/pdf")]
+//!     document: TempFile,
+//! }
+//! ```
+//!
+//! When the `sniff` feature is enabled the
+//! [Sniffed](crate::Sniffed) wrapper can be used to detect the actual type of
+//! the field data from its first bytes, optionally rejecting the fields whose
+//! declared content type does not match.
+//!
 //! ### Field metadata
 //!
 //! If you need access to the field metadata (e.g. the request headers) you can
@@ -156,6 +297,45 @@
 //! }
 //! ```
 //!
+//! Since persisting a file across file systems is not supported, the
+//! directory where the temporary files are created can be configured by adding
+//! a [TempFileConfig](crate::TempFileConfig) extension to the router.
+//! Alternatively the [persist_or_copy](crate::TempFile::persist_or_copy)
+//! method can be used to fall back to copying the data when the file cannot be
+//! moved.
+//!
+//! ### Hashing uploads
+//!
+//! When the `digest` feature is enabled the [Hashed](crate::Hashed) wrapper
+//! can be used to compute the digest of a field while it is being received.
+//! The `sha2`, `sha1`, `md5` and `blake3` features enable the corresponding
+//! algorithms.
+//!
+//! ```rust,ignore
+//! use axum_typed_multipart::{Hashed, Sha256, TempFile, TryFromMultipart};
+//!
+//! #[derive(TryFromMultipart)]
+//! struct RequestData {
+//!     file: Hashed<TempFile, Sha256>,
+//! }
+//! ```
+//!
+//! ### Verifying checksums
+//!
+//! When the `checksum` feature is enabled the [Verified](crate::Verified)
+//! wrapper can be used to reject the fields whose contents do not match the
+//! checksum supplied by the client in the `Content-MD5`, `Digest` or
+//! `Repr-Digest` header of the field.
+//!
+//! ```rust,ignore
+//! use axum_typed_multipart::{TempFile, TryFromMultipart, Verified};
+//!
+//! #[derive(TryFromMultipart)]
+//! struct RequestData {
+//!     file: Verified<TempFile>,
+//! }
+//! ```
+//!
 //! ### Lists
 //!
 //! If the incoming request will include multiple fields that share the same
@@ -177,20 +357,218 @@
 //!     StatusCode::OK
 //! }
 //! ```
+//!
+//! ### Checkboxes
+//!
+//! HTML checkboxes submit `on` (or their `value`) when checked and are omitted
+//! when unchecked. The `checkbox` parameter of the `form_data` attribute parses
+//! a [bool] field treating any value as `true` except for `off`, `0`, `no` and
+//! `false` (case-insensitive), and a missing field as `false`. The
+//! [Checkbox](crate::Checkbox) type applies the same parsing.
+//!
+//! ```rust
+//! use axum_typed_multipart::TryFromMultipart;
+//!
+//! #[derive(TryFromMultipart)]
+//! struct RequestData {
+//!     #[form_data(checkbox)]
+//!     subscribe: bool,
+//! }
+//! ```
+//!
+//! ### Custom scalar types
+//!
+//! Types implementing [FromStr](std::str::FromStr) can be parsed without a
+//! dedicated [TryFromField](crate::TryFromField) implementation by wrapping
+//! them in [FromStrField](crate::FromStrField) or by using the `from_str`
+//! parameter of the `form_data` attribute. The message of the parse error is
+//! included in the returned
+//! [WrongFieldType](crate::TypedMultipartError::WrongFieldType) error.
+//!
+//! ```rust
+//! use axum_typed_multipart::{FromStrField, TryFromMultipart};
+//! use std::num::ParseIntError;
+//! use std::str::FromStr;
+//!
+//! struct Rgb(u32);
+//!
+//! impl FromStr for Rgb {
+//!     type Err = ParseIntError;
+//!
+//!     fn from_str(s: &str) -> Result<Self, Self::Err> {
+//!         u32::from_str_radix(s.trim_start_matches('#'), 16).map(Rgb)
+//!     }
+//! }
+//!
+//! #[derive(TryFromMultipart)]
+//! struct RequestData {
+//!     background: FromStrField<Rgb>,
+//!     #[form_data(from_str)]
+//!     foreground: Rgb,
+//! }
+//! ```
+//!
+//! ### Dates and times
+//!
+//! The `chrono` and `time` features add support for the date and time types of
+//! the corresponding crates. By default the values are parsed using the
+//! formats produced by the HTML `date`, `time` and `datetime-local` inputs,
+//! while the types with a timezone are parsed as RFC 3339 timestamps.
+//!
+//! A different format can be supplied using the `format` parameter of the
+//! `form_data` attribute, written using the syntax of the crate the type
+//! belongs to.
+//!
+//! ```rust,ignore
+//! use axum_typed_multipart::TryFromMultipart;
+//!
+//! #[derive(TryFromMultipart)]
+//! struct RequestData {
+//!     starts_at: chrono::NaiveDateTime,
+//!     #[form_data(format = "%d/%m/%Y")]
+//!     birthday: chrono::NaiveDate,
+//!     #[form_data(format = "[day]/[month]/[year]")]
+//!     anniversary: Option<time::Date>,
+//! }
+//! ```
+//!
+//! ### Enums
+//!
+//! The [TryFromField](crate::TryFromField) trait can be derived for enums
+//! with only unit variants, which is useful for select boxes and radio buttons.
+//!
+//! ```rust
+//! use axum_typed_multipart::{TryFromField, TryFromMultipart};
+//!
+//! #[derive(TryFromField)]
+//! #[form_data(rename_all = "lowercase")]
+//! enum Plan {
+//!     Free,
+//!     Pro,
+//!     #[form_data(rename = "team")]
+//!     Enterprise,
+//! }
+//!
+//! #[derive(TryFromMultipart)]
+//! struct RequestData {
+//!     plan: Plan,
+//! }
+//! ```
+//!
+//! ### Nested structs
+//!
+//! Structs deriving [TryFromMultipart](crate::TryFromMultipart) can be nested
+//! using the `nested` parameter of the `form_data` attribute. The fields of the
+//! nested struct are read from the fields named using either the dot
+//! (`address.city`) or the bracket (`address[city]`) notation, while lists of
+//! structs are read from indexed names such as `items[0][name]`.
+//!
+//! ```rust
+//! use axum_typed_multipart::TryFromMultipart;
+//!
+//! #[derive(TryFromMultipart)]
+//! struct Address {
+//!     street: String,
+//!     city: String,
+//! }
+//!
+//! #[derive(TryFromMultipart)]
+//! struct Item {
+//!     name: String,
+//!     quantity: u32,
+//! }
+//!
+//! #[derive(TryFromMultipart)]
+//! struct RequestData {
+//!     #[form_data(nested)]
+//!     address: Address,
+//!     #[form_data(nested)]
+//!     items: Vec<Item>,
+//! }
+//! ```
+//!
+//! Errors related to nested fields report the full name of the field, e.g.
+//! `items[1][quantity]`.
+//!
+//! ### JSON errors
+//!
+//! When the `json` feature is enabled the errors returned by the
+//! [TypedMultipart](crate::TypedMultipart) extractor will be rendered as JSON,
+//! including a stable error code and the name of the offending field. The
+//! response can also be created explicitly using the
+//! `TypedMultipartError::into_json_response` method.
+//!
+//! ```json
+//! {
+//!     "error": "missing_field",
+//!     "field": "first_name",
+//!     "message": "field 'first_name' is required",
+//!     "expected_type": null
+//! }
+//! ```
 
+#[cfg(feature = "blake3")]
+mod blake3_hasher;
+mod checkbox;
+#[cfg(feature = "chrono")]
+mod chrono_types;
+mod content_type;
 mod field_data;
 mod field_metadata;
+mod field_path;
+mod from_str_field;
+#[cfg(feature = "digest")]
+mod hashed;
+mod limited_body;
+mod persist_or_copy_error;
+mod short_type_name;
+#[cfg(feature = "sniff")]
+mod sniffed;
 mod temp_file;
+mod temp_file_config;
+#[cfg(feature = "time")]
+mod time_types;
+mod try_from_chunks;
 mod try_from_field;
+mod try_from_field_with_format;
 mod try_from_multipart;
+mod try_from_nested_multipart;
 mod typed_multipart;
+mod typed_multipart_config;
 mod typed_multipart_error;
+#[cfg(feature = "checksum")]
+mod verified;
 
+#[cfg(feature = "blake3")]
+pub use crate::blake3_hasher::Blake3;
+pub use crate::checkbox::Checkbox;
+pub use crate::content_type::matches_content_type;
 pub use crate::field_data::FieldData;
 pub use crate::field_metadata::FieldMetadata;
+pub use crate::field_path::{FieldPath, Notation};
+pub use crate::from_str_field::FromStrField;
+#[cfg(feature = "digest")]
+pub use crate::hashed::Hashed;
+pub use crate::persist_or_copy_error::PersistOrCopyError;
+#[cfg(feature = "sniff")]
+pub use crate::sniffed::Sniffed;
 pub use crate::temp_file::TempFile;
+pub use crate::temp_file_config::TempFileConfig;
+pub use crate::try_from_chunks::TryFromChunks;
 pub use crate::try_from_field::TryFromField;
+pub use crate::try_from_field_with_format::TryFromFieldWithFormat;
 pub use crate::try_from_multipart::TryFromMultipart;
+pub use crate::try_from_nested_multipart::TryFromNestedMultipart;
 pub use crate::typed_multipart::TypedMultipart;
-pub use crate::typed_multipart_error::TypedMultipartError;
-pub use axum_typed_multipart_macros::TryFromMultipart;
+pub use crate::typed_multipart_config::TypedMultipartConfig;
+pub use crate::typed_multipart_error::{FieldError, TypedMultipartError};
+#[cfg(feature = "checksum")]
+pub use crate::verified::Verified;
+pub use axum_typed_multipart_macros::{TryFromField, TryFromMultipart};
+
+#[cfg(feature = "md5")]
+pub use md5::Md5;
+#[cfg(feature = "sha1")]
+pub use sha1::Sha1;
+#[cfg(feature = "sha2")]
+pub use sha2::Sha256;
@@ -0,0 +1,208 @@
+use crate::{TypedMultipartConfig, TypedMultipartError};
+use axum::body::{Bytes, HttpBody};
+use axum::http::HeaderMap;
+use axum::BoxError;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{ready, Context, Poll};
+
+/// Request body enforcing the limits of a [TypedMultipartConfig] while the data
+/// is being received.
+///
+/// The error describing the exceeded limit is stored in the shared slot, since
+/// the error returned by the body is wrapped by the multipart parser.
+pub(crate) struct LimitedBody<B> {
+    inner: Pin<Box<B>>,
+    scanner: Scanner,
+    error: Arc<Mutex<Option<TypedMultipartError>>>,
+}
+
+impl<B> LimitedBody<B> {
+    pub(crate) fn new(
+        inner: B,
+        config: TypedMultipartConfig,
+        boundary: Option<String>,
+        error: Arc<Mutex<Option<TypedMultipartError>>>,
+    ) -> Self {
+        Self { inner: Box::pin(inner), scanner: Scanner::new(config, boundary), error }
+    }
+}
+
+impl<B> HttpBody for LimitedBody<B>
+where
+    B: HttpBody,
+    B::Data: Into<Bytes>,
+    B::Error: Into<BoxError>,
+{
+    type Data = Bytes;
+    type Error = BoxError;
+
+    fn poll_data(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        let data = match ready!(self.inner.as_mut().poll_data(cx)) {
+            Some(Ok(data)) => data.into(),
+            Some(Err(error)) => return Poll::Ready(Some(Err(error.into()))),
+            None => return Poll::Ready(None),
+        };
+
+        if let Err(error) = self.scanner.scan(&data) {
+            let message = error.to_string();
+            *self.error.lock().unwrap() = Some(error);
+            return Poll::Ready(Some(Err(message.into())));
+        }
+
+        Poll::Ready(Some(Ok(data)))
+    }
+
+    fn poll_trailers(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        self.inner.as_mut().poll_trailers(cx).map_err(Into::into)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+}
+
+/// Position of the scanner in the multipart body.
+enum State {
+    /// Looking for the next delimiter, e.g. `\r\n--BOUNDARY`.
+    Body,
+    /// Reading the two bytes following a delimiter, which are `--` for the
+    /// closing delimiter.
+    Delimiter(Vec<u8>),
+    /// Reading the headers of a part, until an empty line is found.
+    Headers,
+    /// The closing delimiter was found.
+    Done,
+}
+
+/// Incremental scanner of the multipart body, counting the parts and the size
+/// of their headers as the chunks are received.
+struct Scanner {
+    config: TypedMultipartConfig,
+    delimiter: Option<Vec<u8>>,
+    state: State,
+    /// Number of bytes of the current pattern matched so far.
+    matched: usize,
+    body_size: usize,
+    parts: usize,
+    header_size: usize,
+}
+
+impl Scanner {
+    fn new(config: TypedMultipartConfig, boundary: Option<String>) -> Self {
+        let delimiter = boundary.map(|boundary| format!("\r\n--{boundary}").into_bytes());
+
+        // The first delimiter is not preceded by a line break, so we consider
+        // it already matched.
+        Self {
+            config,
+            delimiter,
+            state: State::Body,
+            matched: 2,
+            body_size: 0,
+            parts: 0,
+            header_size: 0,
+        }
+    }
+
+    fn scan(&mut self, data: &[u8]) -> Result<(), TypedMultipartError> {
+        self.body_size += data.len();
+
+        if let Some(limit_bytes) = self.config.max_body_size {
+            if self.body_size > limit_bytes {
+                return Err(TypedMultipartError::RequestTooLarge { limit_bytes });
+            }
+        }
+
+        if self.config.max_parts.is_none() && self.config.max_part_header_size.is_none() {
+            return Ok(());
+        }
+
+        let Some(delimiter) = self.delimiter.as_deref() else {
+            return Ok(());
+        };
+
+        for &byte in data {
+            match &mut self.state {
+                State::Body => {
+                    if advance(&mut self.matched, delimiter, byte) {
+                        self.state = State::Delimiter(Vec::with_capacity(2));
+                    }
+                }
+                State::Delimiter(bytes) => {
+                    bytes.push(byte);
+
+                    if bytes.len() < 2 {
+                        continue;
+                    }
+
+                    if bytes == b"--" {
+                        self.state = State::Done;
+                        continue;
+                    }
+
+                    self.parts += 1;
+
+                    if let Some(limit) = self.config.max_parts {
+                        if self.parts > limit {
+                            return Err(TypedMultipartError::TooManyParts { limit });
+                        }
+                    }
+
+                    // The line break following the delimiter is the first
+                    // half of the empty line when the part has no headers.
+                    self.matched = 0;
+                    advance(&mut self.matched, b"\r\n\r\n", bytes[0]);
+                    advance(&mut self.matched, b"\r\n\r\n", bytes[1]);
+
+                    self.header_size = 0;
+                    self.state = State::Headers;
+                }
+                State::Headers => {
+                    self.header_size += 1;
+
+                    if let Some(limit_bytes) = self.config.max_part_header_size {
+                        if self.header_size > limit_bytes {
+                            return Err(TypedMultipartError::PartHeadersTooLarge { limit_bytes });
+                        }
+                    }
+
+                    if advance(&mut self.matched, b"\r\n\r\n", byte) {
+                        self.matched = 0;
+                        self.state = State::Body;
+                    }
+                }
+                State::Done => break,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Advance the match of the supplied `pattern` with the next `byte`, returning
+/// `true` when the whole pattern is matched.
+///
+/// On a mismatch the match is restarted from the current byte, which is enough
+/// for the patterns used by the scanner since none of their proper prefixes
+/// end with a longer prefix than `\r`.
+fn advance(matched: &mut usize, pattern: &[u8], byte: u8) -> bool {
+    if pattern[*matched] == byte {
+        *matched += 1;
+    } else {
+        *matched = usize::from(pattern[0] == byte);
+    }
+
+    if *matched == pattern.len() {
+        *matched = 0;
+        return true;
+    }
+
+    false
+}
@@ -0,0 +1,25 @@
+use crate::TempFile;
+use std::io;
+use std::path::PathBuf;
+
+/// Error returned by [TempFile::persist_or_copy](crate::TempFile::persist_or_copy).
+///
+/// The temporary file is handed back in the `file` field, so the data is not
+/// lost and the operation can be retried.
+#[derive(thiserror::Error, Debug)]
+pub enum PersistOrCopyError {
+    #[error("file '{}' already exists", .path.display())]
+    AlreadyExists { path: PathBuf, file: TempFile },
+
+    #[error("failed to persist the file to '{}' ({source})", .path.display())]
+    Io { path: PathBuf, source: io::Error, file: TempFile },
+}
+
+impl PersistOrCopyError {
+    /// Get back the temporary file that could not be persisted.
+    pub fn into_file(self) -> TempFile {
+        match self {
+            Self::AlreadyExists { file, .. } | Self::Io { file, .. } => file,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+/// Strip the module paths from the supplied type name, e.g.
+/// `alloc::vec::Vec<my_crate::Sku>` becomes `Vec<Sku>`, since they are not
+/// relevant to the clients.
+pub(crate) fn short_type_name(name: &str) -> String {
+    let mut output = String::with_capacity(name.len());
+    let mut segment_start = 0;
+    let mut chars = name.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        if char == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            output.truncate(segment_start);
+            continue;
+        }
+
+        output.push(char);
+
+        if !char.is_alphanumeric() && char != '_' {
+            segment_start = output.len();
+        }
+    }
+
+    output
+}
@@ -0,0 +1,77 @@
+use crate::{FieldMetadata, TryFromChunks, TypedMultipartError};
+use axum::async_trait;
+use axum::body::Bytes;
+use futures_util::stream::{self, Stream, StreamExt};
+
+/// Number of bytes inspected to detect the type of the field data.
+const SNIFF_BYTES: usize = 8192;
+
+/// Wrapper detecting the actual type of the field data from its first bytes
+/// (AKA magic bytes), since the `Content-Type` declared by the client cannot be
+/// trusted.
+///
+/// When `STRICT` is `true` a
+/// [ContentTypeMismatch](crate::TypedMultipartError::ContentTypeMismatch) error
+/// is returned if the detected type does not match the declared one. Note that
+/// the check is skipped when the type cannot be detected or is not declared.
+///
+/// ## Example
+///
+/// ```rust
+/// use axum_typed_multipart::{Sniffed, TempFile, TryFromMultipart};
+///
+/// #[derive(TryFromMultipart)]
+/// struct FileUpload {
+///     image: Sniffed<TempFile>,
+///     document: Sniffed<TempFile, true>,
+/// }
+/// ```
+pub struct Sniffed<T, const STRICT: bool = false> {
+    pub contents: T,
+    /// MIME type detected from the field data, if known.
+    pub detected_type: Option<String>,
+}
+
+#[async_trait]
+impl<T: TryFromChunks + Send, const STRICT: bool> TryFromChunks for Sniffed<T, STRICT> {
+    async fn try_from_chunks(
+        mut chunks: impl Stream<Item = Result<Bytes, TypedMultipartError>> + Send + Unpin,
+        metadata: FieldMetadata,
+    ) -> Result<Self, TypedMultipartError> {
+        let mut buffer = Vec::new();
+        let mut head = Vec::new();
+
+        while buffer.len() < SNIFF_BYTES {
+            match chunks.next().await {
+                Some(chunk) => {
+                    let chunk = chunk?;
+                    buffer.extend_from_slice(&chunk);
+                    head.push(Ok(chunk));
+                }
+                None => break,
+            }
+        }
+
+        let detected_type = infer::get(&buffer).map(|kind| kind.mime_type().to_string());
+
+        if let (true, Some(detected), Some(declared)) =
+            (STRICT, &detected_type, &metadata.content_type)
+        {
+            let essence = declared.split(';').next().unwrap_or_default().trim();
+
+            if !essence.eq_ignore_ascii_case(detected) {
+                return Err(TypedMultipartError::ContentTypeMismatch {
+                    field_name: metadata.name.unwrap_or_default(),
+                    declared: declared.to_string(),
+                    detected: detected.to_string(),
+                });
+            }
+        }
+
+        // Pass the inspected chunks on to the inner type before the rest.
+        let chunks = stream::iter(head).chain(chunks);
+        let contents = T::try_from_chunks(chunks, metadata).await?;
+
+        Ok(Self { contents, detected_type })
+    }
+}
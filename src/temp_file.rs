@@ -1,10 +1,15 @@
-use crate::{TryFromField, TypedMultipartError};
+use crate::{
+    FieldMetadata, PersistOrCopyError, TempFileConfig, TryFromChunks, TypedMultipartError,
+};
 use axum::async_trait;
-use axum::extract::multipart::Field;
+use axum::body::Bytes;
+use futures_util::stream::{Stream, StreamExt};
 use std::fs::File;
-use std::io::Write;
-use std::path::Path;
+use std::io::{self, ErrorKind, SeekFrom};
+use std::path::{Path, PathBuf};
 use tempfile::{NamedTempFile, PersistError};
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+use tokio::task::spawn_blocking;
 
 /// Stream the field data on the file system using a temporary file.
 ///
@@ -18,6 +23,9 @@ use tempfile::{NamedTempFile, PersistError};
 /// not be deleted. For more details about this check the [NamedTempFile]
 /// documentation.
 ///
+/// The location and the name of the temporary file can be customized using
+/// [TempFileConfig].
+///
 /// ## Example
 /// ```rust
 /// use axum_typed_multipart::{TempFile, TryFromMultipart, TypedMultipart};
@@ -27,9 +35,15 @@ use tempfile::{NamedTempFile, PersistError};
 ///     file: TempFile,
 /// }
 /// ```
+#[derive(Debug)]
 pub struct TempFile(NamedTempFile);
 
 impl TempFile {
+    /// Get the path of the temporary file.
+    pub fn path(&self) -> &Path {
+        self.0.path()
+    }
+
     /// Persist the data permanently at the supplied `path`.
     ///
     /// When `replace` is `true` the file at the target path will be replaced if
@@ -44,17 +58,194 @@ impl TempFile {
             false => self.0.persist_noclobber(path),
         }
     }
+
+    /// Persist the data permanently at the supplied `path`, falling back to
+    /// copying the data and deleting the temporary file when it cannot be
+    /// moved, e.g. because the target path is on a different file system.
+    /// The data is copied to a new file next to the target and then moved in
+    /// place, so the existing file is never left partially written.
+    ///
+    /// When `replace` is `true` the file at the target path will be replaced if
+    /// it exists, otherwise an
+    /// [AlreadyExists](PersistOrCopyError::AlreadyExists) error is returned.
+    ///
+    /// On failure the temporary file is returned inside the error, so the
+    /// operation can be retried without losing the data.
+    pub async fn persist_or_copy<P: AsRef<Path>>(
+        self,
+        path: P,
+        replace: bool,
+    ) -> Result<File, PersistOrCopyError> {
+        let path = path.as_ref();
+
+        let result = match replace {
+            true => self.0.persist(path),
+            false => self.0.persist_noclobber(path),
+        };
+
+        let PersistError { error, file } = match result {
+            Ok(file) => return Ok(file),
+            Err(error) => error,
+        };
+
+        if error.kind() == ErrorKind::AlreadyExists && !replace {
+            let file = TempFile(file);
+            return Err(PersistOrCopyError::AlreadyExists { path: path.to_path_buf(), file });
+        }
+
+        copy_and_delete(file, path, replace).await.map_err(|(source, file)| {
+            let file = TempFile(file);
+
+            match source.kind() {
+                ErrorKind::AlreadyExists => {
+                    PersistOrCopyError::AlreadyExists { path: path.to_path_buf(), file }
+                }
+                _ => PersistOrCopyError::Io { path: path.to_path_buf(), source, file },
+            }
+        })
+    }
+}
+
+/// Copy the contents of the temporary `file` to the supplied `path` and delete
+/// it, handing the temporary file back on failure.
+async fn copy_and_delete(
+    file: NamedTempFile,
+    path: &Path,
+    replace: bool,
+) -> Result<File, (io::Error, NamedTempFile)> {
+    match copy(&file, path, replace).await {
+        // The data is already stored at the target path, so failing to remove
+        // the temporary file must not undo the operation.
+        Ok(target) => {
+            let _ = spawn_blocking(move || file.close()).await;
+            Ok(target)
+        }
+        Err(error) => Err((error, file)),
+    }
+}
+
+/// Copy the contents of the temporary `file` to a new temporary file in the
+/// directory of the supplied `path`, then atomically move it to `path`.
+///
+/// The existing file at `path`, if any, is left untouched on failure, since
+/// only the file created by this function is removed.
+async fn copy(file: &NamedTempFile, path: &Path, replace: bool) -> io::Result<File> {
+    let mut source = tokio::fs::File::from_std(file.as_file().try_clone()?);
+    source.seek(SeekFrom::Start(0)).await?;
+
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+
+    let staging = spawn_blocking(move || NamedTempFile::new_in(dir)).await??;
+    let mut target = tokio::fs::File::from_std(staging.as_file().try_clone()?);
+
+    tokio::io::copy(&mut source, &mut target).await?;
+    target.flush().await?;
+    target.sync_all().await?;
+
+    let path = path.to_path_buf();
+
+    spawn_blocking(move || match replace {
+        true => staging.persist(path),
+        false => staging.persist_noclobber(path),
+    })
+    .await?
+    .map_err(|error| error.error)
 }
 
 #[async_trait]
-impl TryFromField for TempFile {
-    async fn try_from_field(mut field: Field<'_>) -> Result<Self, TypedMultipartError> {
-        let mut file = NamedTempFile::new().map_err(anyhow::Error::new)?;
+impl TryFromChunks for TempFile {
+    async fn try_from_chunks(
+        mut chunks: impl Stream<Item = Result<Bytes, TypedMultipartError>> + Send + Unpin,
+        _: FieldMetadata,
+    ) -> Result<Self, TypedMultipartError> {
+        let config = TempFileConfig::current();
+        let temp_file =
+            spawn_blocking(move || config.create()).await.map_err(anyhow::Error::new)??;
+
+        // The file is written asynchronously to avoid blocking the runtime,
+        // while the path is kept aside so the file is deleted on failure.
+        let (file, path) = temp_file.into_parts();
+        let mut file = tokio::fs::File::from_std(file);
 
-        while let Some(chunk) = field.chunk().await? {
-            file.write(&chunk).map_err(anyhow::Error::new)?;
+        while let Some(chunk) = chunks.next().await {
+            file.write_all(&chunk?).await?;
         }
 
-        Ok(TempFile(file))
+        file.flush().await?;
+
+        Ok(TempFile(NamedTempFile::from_parts(file.into_std().await, path)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[tokio::test]
+    async fn test_copy_and_delete() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("potato.txt");
+
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"Potato!").unwrap();
+        let temp_path = file.path().to_path_buf();
+
+        copy_and_delete(file, &path, false).await.unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Potato!");
+        assert!(!temp_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_copy_and_delete_failure() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("potato.txt");
+        std::fs::write(&path, "Tomato!").unwrap();
+
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"Potato!").unwrap();
+
+        let (error, file) = copy_and_delete(file, &path, false).await.unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::AlreadyExists);
+        assert_eq!(std::fs::read_to_string(file.path()).unwrap(), "Potato!");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Tomato!");
+    }
+
+    #[tokio::test]
+    async fn test_copy_and_delete_replace() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("potato.txt");
+        std::fs::write(&path, "Tomato!").unwrap();
+
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"Potato!").unwrap();
+
+        copy_and_delete(file, &path, true).await.unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Potato!");
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_copy_and_delete_replace_failure() {
+        // Replacing a non-empty directory fails once the data is copied.
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("potato");
+        std::fs::create_dir(&path).unwrap();
+        std::fs::write(path.join("tomato.txt"), "Tomato!").unwrap();
+
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"Potato!").unwrap();
+
+        let (_, file) = copy_and_delete(file, &path, true).await.unwrap_err();
+
+        assert_eq!(std::fs::read_to_string(file.path()).unwrap(), "Potato!");
+        assert_eq!(std::fs::read_to_string(path.join("tomato.txt")).unwrap(), "Tomato!");
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 1);
     }
 }
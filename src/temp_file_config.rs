@@ -0,0 +1,102 @@
+use std::future::Future;
+use std::io;
+use std::path::PathBuf;
+use tempfile::{Builder, NamedTempFile};
+
+tokio::task_local! {
+    static TEMP_FILE_CONFIG: TempFileConfig;
+}
+
+/// Configuration used to create the temporary files backing the
+/// [TempFile](crate::TempFile) fields.
+///
+/// By default the files are created in the system temporary directory, which
+/// might be on a different file system than the final destination of the
+/// uploads, preventing them from being persisted. The configuration can be
+/// supplied to the [TypedMultipart](crate::TypedMultipart) extractor by adding
+/// it as an [Extension](axum::Extension) to the router.
+///
+/// ## Example
+///
+/// ```rust
+/// use axum::routing::post;
+/// use axum::{Extension, Router};
+/// use axum_typed_multipart::{TempFile, TempFileConfig, TryFromMultipart, TypedMultipart};
+///
+/// #[derive(TryFromMultipart)]
+/// struct FileUpload {
+///     file: TempFile,
+/// }
+///
+/// async fn upload(TypedMultipart(FileUpload { file }): TypedMultipart<FileUpload>) {
+///     // ...
+/// }
+///
+/// let config = TempFileConfig::new().dir("/var/lib/uploads/tmp").prefix("upload-");
+/// let app: Router = Router::new().route("/", post(upload)).layer(Extension(config));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TempFileConfig {
+    dir: Option<PathBuf>,
+    prefix: Option<String>,
+    suffix: Option<String>,
+}
+
+impl TempFileConfig {
+    /// Create a configuration using the system defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the directory where the temporary files are created.
+    pub fn dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    /// Set the prefix of the names of the temporary files.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Set the suffix of the names of the temporary files, e.g. an extension.
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Run the supplied future using this configuration for all the
+    /// temporary files created while it is running.
+    ///
+    /// This is done automatically by the [TypedMultipart](crate::TypedMultipart)
+    /// extractor, but can be useful when calling
+    /// [TryFromMultipart](crate::TryFromMultipart) directly.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        TEMP_FILE_CONFIG.scope(self, future).await
+    }
+
+    /// Get the configuration of the current scope, falling back to the
+    /// default one.
+    pub(crate) fn current() -> Self {
+        TEMP_FILE_CONFIG.try_with(Clone::clone).unwrap_or_default()
+    }
+
+    /// Create a new temporary file using this configuration.
+    pub(crate) fn create(&self) -> io::Result<NamedTempFile> {
+        let mut builder = Builder::new();
+
+        if let Some(prefix) = &self.prefix {
+            builder.prefix(prefix);
+        }
+
+        if let Some(suffix) = &self.suffix {
+            builder.suffix(suffix);
+        }
+
+        match &self.dir {
+            Some(dir) => builder.tempfile_in(dir),
+            None => builder.tempfile(),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::try_from_field_with_format::{gen_date_time_impl, parse_any};
+use time::format_description::well_known::Rfc3339;
+use time::format_description::{self, BorrowedFormatItem};
+use time::macros::format_description;
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+/// Format produced by the HTML `date` input.
+const DATE_FORMAT: &[BorrowedFormatItem] = format_description!("[year]-[month]-[day]");
+
+/// Formats produced by the HTML `time` input, with optional seconds.
+const TIME_FORMATS: [&[BorrowedFormatItem]; 3] = [
+    format_description!("[hour]:[minute]:[second].[subsecond]"),
+    format_description!("[hour]:[minute]:[second]"),
+    format_description!("[hour]:[minute]"),
+];
+
+/// Formats produced by the HTML `datetime-local` input, with optional seconds.
+const DATE_TIME_FORMATS: [&[BorrowedFormatItem]; 3] = [
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond]"),
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]"),
+    format_description!("[year]-[month]-[day]T[hour]:[minute]"),
+];
+
+gen_date_time_impl!(
+    time::Date,
+    |text| Date::parse(text, DATE_FORMAT),
+    |format| format_description::parse_owned::<1>(format),
+    |text, format| Date::parse(text, format),
+);
+
+gen_date_time_impl!(
+    time::Time,
+    |text| parse_any(&TIME_FORMATS, |format| Time::parse(text, format)),
+    |format| format_description::parse_owned::<1>(format),
+    |text, format| Time::parse(text, format),
+);
+
+gen_date_time_impl!(
+    time::PrimitiveDateTime,
+    |text| parse_any(&DATE_TIME_FORMATS, |format| PrimitiveDateTime::parse(text, format)),
+    |format| format_description::parse_owned::<1>(format),
+    |text, format| PrimitiveDateTime::parse(text, format),
+);
+
+gen_date_time_impl!(
+    time::OffsetDateTime,
+    |text| OffsetDateTime::parse(text, &Rfc3339),
+    |format| format_description::parse_owned::<1>(format),
+    |text, format| OffsetDateTime::parse(text, format),
+);
@@ -0,0 +1,97 @@
+use crate::{FieldMetadata, TypedMultipartError};
+use axum::async_trait;
+use axum::body::Bytes;
+use futures_util::stream::Stream;
+use futures_util::TryStreamExt;
+use encoding_rs::{Encoding, UTF_8};
+
+/// Types that can be created from a stream of [Bytes] chunks.
+///
+/// This is the preferred way to implement custom field types since the
+/// [TryFromField](crate::TryFromField) trait is implemented automatically for
+/// all types implementing this trait, enforcing the size limit configured
+/// for the field on the incoming chunks.
+///
+/// ## Example
+///
+/// ```rust
+/// use axum::async_trait;
+/// use axum::body::Bytes;
+/// use axum_typed_multipart::{FieldMetadata, TryFromChunks, TypedMultipartError};
+/// use futures_util::stream::Stream;
+/// use futures_util::TryStreamExt;
+///
+/// struct Data(Vec<u8>);
+///
+/// #[async_trait]
+/// impl TryFromChunks for Data {
+///     async fn try_from_chunks(
+///         chunks: impl Stream<Item = Result<Bytes, TypedMultipartError>> + Send + Unpin,
+///         _: FieldMetadata,
+///     ) -> Result<Self, TypedMultipartError> {
+///         let chunks = chunks.try_collect::<Vec<_>>().await?;
+///         Ok(Data(chunks.concat()))
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait TryFromChunks: Sized {
+    /// Consume the input stream of chunks to create the supplied type.
+    ///
+    /// The `metadata` parameter contains information about the field the
+    /// chunks are coming from.
+    async fn try_from_chunks(
+        chunks: impl Stream<Item = Result<Bytes, TypedMultipartError>> + Send + Unpin,
+        metadata: FieldMetadata,
+    ) -> Result<Self, TypedMultipartError>;
+}
+
+#[async_trait]
+impl TryFromChunks for Bytes {
+    async fn try_from_chunks(
+        chunks: impl Stream<Item = Result<Bytes, TypedMultipartError>> + Send + Unpin,
+        _: FieldMetadata,
+    ) -> Result<Self, TypedMultipartError> {
+        let bytes = chunks
+            .try_fold(Vec::new(), |mut bytes, chunk| async move {
+                bytes.extend_from_slice(&chunk);
+                Ok(bytes)
+            })
+            .await?;
+
+        Ok(Bytes::from(bytes))
+    }
+}
+
+/// The text is decoded using the `charset` parameter of the content type of the
+/// field, falling back to UTF-8, and invalid sequences are replaced with
+/// `U+FFFD REPLACEMENT CHARACTER`.
+#[async_trait]
+impl TryFromChunks for String {
+    async fn try_from_chunks(
+        chunks: impl Stream<Item = Result<Bytes, TypedMultipartError>> + Send + Unpin,
+        metadata: FieldMetadata,
+    ) -> Result<Self, TypedMultipartError> {
+        let encoding = metadata
+            .content_type
+            .as_deref()
+            .and_then(get_charset)
+            .and_then(|charset| Encoding::for_label(charset.as_bytes()))
+            .unwrap_or(UTF_8);
+        let bytes = Bytes::try_from_chunks(chunks, metadata).await?;
+        let (text, _, _) = encoding.decode(&bytes);
+
+        Ok(text.into_owned())
+    }
+}
+
+/// Extract the `charset` parameter from the supplied content type.
+fn get_charset(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        match name.trim().eq_ignore_ascii_case("charset") {
+            true => Some(value.trim().trim_matches('"')),
+            false => None,
+        }
+    })
+}
@@ -1,14 +1,26 @@
-use crate::TypedMultipartError;
+use crate::short_type_name::short_type_name;
+use crate::{FieldMetadata, TryFromChunks, TypedMultipartError};
 use axum::async_trait;
 use axum::body::Bytes;
 use axum::extract::multipart::Field;
-use std::any::type_name;
+use futures_util::stream::{Stream, StreamExt};
+use futures_util::TryStreamExt;
+use std::mem;
 
 /// Types that can be created from an instance of [Field].
 ///
 /// All fields for a given struct must implement this trait to be able to derive
 /// the [TryFromMultipart](crate::TryFromMultipart) trait.
 ///
+/// The trait is implemented automatically for all types that implement
+/// [TryFromChunks], which should be preferred for custom types since the size
+/// limit of the field will be enforced for you.
+///
+/// Hand-written implementations of this trait are responsible for enforcing
+/// the `limit` parameter of the `form_data` attribute themselves: the limit is
+/// only passed in as `limit_bytes`, and it is silently ignored unless the
+/// implementation checks it.
+///
 /// ## Example
 ///
 /// ```rust
@@ -20,31 +32,117 @@ use std::any::type_name;
 ///
 /// #[async_trait]
 /// impl TryFromField for Foo {
-///     async fn try_from_field(field: Field<'_>) -> Result<Self, TypedMultipartError> {
+///     async fn try_from_field(
+///         field: Field<'_>,
+///         limit_bytes: Option<usize>,
+///     ) -> Result<Self, TypedMultipartError> {
+///         let field_name = field.name().unwrap_or_default().to_string();
 ///         let text = field.text().await?;
-///         Ok(Foo(text))
+///
+///         match limit_bytes {
+///             Some(limit_bytes) if text.len() > limit_bytes => {
+///                 Err(TypedMultipartError::FieldTooLarge { field_name, limit_bytes })
+///             }
+///             _ => Ok(Foo(text)),
+///         }
 ///     }
 /// }
 /// ```
+///
+/// ## Derive macro
+///
+/// The trait can be derived for enums with only unit variants, which are
+/// matched against the text of the field. A
+/// [WrongFieldType](crate::TypedMultipartError::WrongFieldType) error listing
+/// the allowed values is returned when no variant matches.
+///
+/// The `form_data` attribute can be used to customize the expected values:
+///
+/// - `rename_all` => Case convention applied to all the variant names, one of
+/// `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`,
+/// `"snake_case"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"` or
+/// `"SCREAMING-KEBAB-CASE"`. The names are converted in the same way as serde,
+/// so every uppercase letter starts a new word (e.g. `HTTPPost` becomes
+/// `H_T_T_P_POST` with `"SCREAMING_SNAKE_CASE"`).
+///
+/// - `rename` => Set the expected value for a single variant, taking precedence
+/// over `rename_all`.
+///
+/// ```rust
+/// use axum_typed_multipart::TryFromField;
+///
+/// #[derive(TryFromField)]
+/// #[form_data(rename_all = "snake_case")]
+/// enum Color {
+///     Red,
+///     DarkGreen,
+///     #[form_data(rename = "azure")]
+///     Blue,
+/// }
+/// ```
 #[async_trait]
 pub trait TryFromField: Sized {
     /// Consume the input [Field] to create the supplied type.
-    async fn try_from_field(field: Field<'_>) -> Result<Self, TypedMultipartError>;
+    ///
+    /// When `limit_bytes` is supplied a
+    /// [FieldTooLarge](crate::TypedMultipartError::FieldTooLarge) error should
+    /// be returned if the field contents exceed the specified size, since the
+    /// limit is not enforced by the caller.
+    async fn try_from_field(
+        field: Field<'_>,
+        limit_bytes: Option<usize>,
+    ) -> Result<Self, TypedMultipartError>;
 }
 
-/// Generate a [TryFromField] implementation for the supplied type using the
+#[async_trait]
+impl<T: TryFromChunks + Send> TryFromField for T {
+    async fn try_from_field(
+        field: Field<'_>,
+        limit_bytes: Option<usize>,
+    ) -> Result<Self, TypedMultipartError> {
+        let metadata = FieldMetadata::from(&field);
+        let mut field_name = metadata.name.clone().unwrap_or_default();
+        let mut size_bytes = 0;
+
+        let chunks = field.map_err(TypedMultipartError::from).map(move |chunk| {
+            if let (Ok(chunk), Some(limit_bytes)) = (&chunk, limit_bytes) {
+                size_bytes += chunk.len();
+
+                if size_bytes > limit_bytes {
+                    return Err(TypedMultipartError::FieldTooLarge {
+                        field_name: mem::take(&mut field_name),
+                        limit_bytes,
+                    });
+                }
+            }
+
+            chunk
+        });
+
+        T::try_from_chunks(chunks, metadata).await
+    }
+}
+
+/// Generate a [TryFromChunks] implementation for the supplied type using the
 /// `str::parse` method on the text representation of the field data.
+///
+/// The type is reported in errors without its module path (e.g. `IpAddr`),
+/// consistently with [FromStrField](crate::FromStrField).
 macro_rules! gen_try_from_field_impl {
     ( $type: ty ) => {
         #[async_trait]
-        impl TryFromField for $type {
-            async fn try_from_field(field: Field<'_>) -> Result<Self, TypedMultipartError> {
-                let field_name = field.name().unwrap().to_string();
-                let text = field.text().await?;
+        impl TryFromChunks for $type {
+            async fn try_from_chunks(
+                chunks: impl Stream<Item = Result<Bytes, TypedMultipartError>> + Send + Unpin,
+                metadata: FieldMetadata,
+            ) -> Result<Self, TypedMultipartError> {
+                let field_name = metadata.name.clone().ok_or(TypedMultipartError::NamelessField)?;
+                let text = String::try_from_chunks(chunks, metadata).await?;
 
-                str::parse(&text).map_err(move |_| TypedMultipartError::WrongFieldType {
+                str::parse(&text).map_err(move |error| TypedMultipartError::WrongFieldType {
                     field_name,
-                    wanted_type: type_name::<$type>().to_string(),
+                    wanted_type: short_type_name(stringify!($type)),
+                    source: Some(Box::new(error)),
                 })
             }
         }
@@ -65,21 +163,32 @@ gen_try_from_field_impl!(u128);
 gen_try_from_field_impl!(usize);
 gen_try_from_field_impl!(f32);
 gen_try_from_field_impl!(f64);
-gen_try_from_field_impl!(bool); // TODO?: Consider accepting any thruthy value.
+gen_try_from_field_impl!(bool); // See `Checkbox` for a lenient alternative.
 gen_try_from_field_impl!(char);
-
-#[async_trait]
-impl TryFromField for String {
-    async fn try_from_field(field: Field<'_>) -> Result<Self, TypedMultipartError> {
-        let text = field.text().await?;
-        Ok(text)
-    }
-}
-
-#[async_trait]
-impl TryFromField for Bytes {
-    async fn try_from_field(field: Field<'_>) -> Result<Self, TypedMultipartError> {
-        let bytes = field.bytes().await?;
-        Ok(bytes)
-    }
-}
+gen_try_from_field_impl!(std::num::NonZeroI8);
+gen_try_from_field_impl!(std::num::NonZeroI16);
+gen_try_from_field_impl!(std::num::NonZeroI32);
+gen_try_from_field_impl!(std::num::NonZeroI64);
+gen_try_from_field_impl!(std::num::NonZeroI128);
+gen_try_from_field_impl!(std::num::NonZeroIsize);
+gen_try_from_field_impl!(std::num::NonZeroU8);
+gen_try_from_field_impl!(std::num::NonZeroU16);
+gen_try_from_field_impl!(std::num::NonZeroU32);
+gen_try_from_field_impl!(std::num::NonZeroU64);
+gen_try_from_field_impl!(std::num::NonZeroU128);
+gen_try_from_field_impl!(std::num::NonZeroUsize);
+gen_try_from_field_impl!(std::net::IpAddr);
+gen_try_from_field_impl!(std::net::Ipv4Addr);
+gen_try_from_field_impl!(std::net::Ipv6Addr);
+gen_try_from_field_impl!(std::net::SocketAddr);
+gen_try_from_field_impl!(std::net::SocketAddrV4);
+gen_try_from_field_impl!(std::net::SocketAddrV6);
+gen_try_from_field_impl!(std::path::PathBuf);
+#[cfg(feature = "rust_decimal")]
+gen_try_from_field_impl!(rust_decimal::Decimal);
+#[cfg(feature = "semver")]
+gen_try_from_field_impl!(semver::Version);
+#[cfg(feature = "url")]
+gen_try_from_field_impl!(url::Url);
+#[cfg(feature = "uuid")]
+gen_try_from_field_impl!(uuid::Uuid);
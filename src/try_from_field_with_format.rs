@@ -0,0 +1,127 @@
+use crate::TypedMultipartError;
+use axum::async_trait;
+use axum::extract::multipart::Field;
+
+/// Types that can be created from an instance of [Field] using a custom
+/// format, supplied through the `format` parameter of the `form_data`
+/// attribute.
+///
+/// The trait is implemented for the date and time types of the `chrono` and
+/// `time` crates when the corresponding features are enabled. The syntax of
+/// the format is the one used by the crate the type belongs to, and an
+/// invalid format results in an [Other](TypedMultipartError::Other) error,
+/// since it is a mistake of the server rather than of the client.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use axum_typed_multipart::TryFromMultipart;
+///
+/// #[derive(TryFromMultipart)]
+/// struct RequestData {
+///     #[form_data(format = "%d/%m/%Y")]
+///     birthday: chrono::NaiveDate,
+///     #[form_data(format = "[day]/[month]/[year]")]
+///     anniversary: time::Date,
+/// }
+/// ```
+#[async_trait]
+pub trait TryFromFieldWithFormat: Sized {
+    /// Consume the input [Field] to create the supplied type, parsing its
+    /// contents using the supplied `format`.
+    ///
+    /// When `limit_bytes` is supplied an error should be returned if the field
+    /// contents exceed the specified size.
+    async fn try_from_field_with_format(
+        field: Field<'_>,
+        limit_bytes: Option<usize>,
+        format: &str,
+    ) -> Result<Self, TypedMultipartError>;
+}
+
+/// Generate the [TryFromChunks](crate::TryFromChunks) and
+/// [TryFromFieldWithFormat] implementations for a date or time type.
+///
+/// The supplied expressions parse the `text` using the default formats, check
+/// the custom `format` (an invalid format is a server error rather than a
+/// client one) and parse the `text` using the checked format respectively.
+#[cfg(any(feature = "chrono", feature = "time"))]
+macro_rules! gen_date_time_impl {
+    (
+        $type: ty,
+        |$text: ident| $parse: expr,
+        |$format: ident| $check_format: expr,
+        |$checked_text: ident, $checked_format: ident| $parse_with_format: expr $(,)?
+    ) => {
+        #[axum::async_trait]
+        impl $crate::TryFromChunks for $type {
+            async fn try_from_chunks(
+                chunks: impl futures_util::stream::Stream<
+                        Item = Result<axum::body::Bytes, $crate::TypedMultipartError>,
+                    > + Send
+                    + Unpin,
+                metadata: $crate::FieldMetadata,
+            ) -> Result<Self, $crate::TypedMultipartError> {
+                let field_name =
+                    metadata.name.clone().ok_or($crate::TypedMultipartError::NamelessField)?;
+                let text =
+                    <String as $crate::TryFromChunks>::try_from_chunks(chunks, metadata).await?;
+                let $text: &str = &text;
+
+                $parse.map_err(|error| $crate::TypedMultipartError::WrongFieldType {
+                    field_name,
+                    wanted_type: $crate::short_type_name::short_type_name(stringify!($type)),
+                    source: Some(Box::new(error)),
+                })
+            }
+        }
+
+        #[axum::async_trait]
+        impl $crate::TryFromFieldWithFormat for $type {
+            async fn try_from_field_with_format(
+                field: axum::extract::multipart::Field<'_>,
+                limit_bytes: Option<usize>,
+                format: &str,
+            ) -> Result<Self, $crate::TypedMultipartError> {
+                let $format: &str = format;
+                let checked_format = $check_format.map_err(|error| {
+                    anyhow::anyhow!(
+                        "invalid format '{format}' for type '{}' ({error})",
+                        $crate::short_type_name::short_type_name(stringify!($type))
+                    )
+                })?;
+
+                let field_name = field
+                    .name()
+                    .map(String::from)
+                    .ok_or($crate::TypedMultipartError::NamelessField)?;
+                let text =
+                    <String as $crate::TryFromField>::try_from_field(field, limit_bytes).await?;
+                let ($checked_text, $checked_format) = (text.as_str(), &checked_format);
+
+                $parse_with_format.map_err(|error| $crate::TypedMultipartError::WrongFieldType {
+                    field_name,
+                    wanted_type: format!(
+                        "{} ({format})",
+                        $crate::short_type_name::short_type_name(stringify!($type))
+                    ),
+                    source: Some(Box::new(error)),
+                })
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) use gen_date_time_impl;
+
+/// Parse a value using each of the supplied default `formats` in turn,
+/// returning the error of the last one when none of them matches.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn parse_any<F: Copy, T, E>(
+    formats: &[F],
+    parse: impl Fn(F) -> Result<T, E>,
+) -> Result<T, E> {
+    let (first, rest) = formats.split_first().expect("at least one format is required");
+    rest.iter().fold(parse(*first), |result, format| result.or_else(|_| parse(*format)))
+}
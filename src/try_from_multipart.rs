@@ -21,18 +21,97 @@ use axum::extract::Multipart;
 /// exception of [Option] and [Vec] types, which will be set respectively as
 /// [Option::None] and `[]`.
 ///
+/// Generic structs are supported as well: the
+/// [TryFromField](crate::TryFromField) bound is added automatically to the
+/// fields that depend on the type parameters.
+///
 /// ### `form_data` attribute
 ///
-/// Can be applied to the struct fields to configure the parser behaviour.
+/// Can be applied to the struct and its fields to configure the parser
+/// behaviour.
+///
+/// #### Struct arguments
+///
+/// - `strict` => Return an [UnknownField](crate::TypedMultipartError::UnknownField)
+/// error when the request contains a field that does not match any of the
+/// struct fields, instead of silently ignoring it.
+///
+/// - `duplicate` => Default strategy used for all fields when a non-list field
+/// is supplied more than once, see the corresponding field argument.
+///
+/// - `skip_nameless` => Ignore the fields without a name in the
+/// `Content-Disposition` header instead of returning a
+/// [NamelessField](crate::TypedMultipartError::NamelessField) error.
 ///
-/// #### Arguments
+/// - `collect_errors` => Keep parsing the request when a field is missing or
+/// invalid and return all the field errors at once using the
+/// [Multiple](crate::TypedMultipartError::Multiple) variant.
+///
+/// - `rename_all` => Case convention applied to the names of all the fields,
+/// one of `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`,
+/// `"snake_case"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"` or
+/// `"SCREAMING-KEBAB-CASE"`. The names are converted in the same way as serde
+/// and the `field_name` argument takes precedence.
+///
+/// - `max_fields` => Stop reading the request and return a
+/// [TooManyFields](crate::TypedMultipartError::TooManyFields) error when the
+/// request contains more than the supplied number of fields. Only enforced on
+/// the outermost struct.
+///
+/// #### Field arguments
 ///
 /// - `field_name` => Can be used to configure a different name for the source
 /// field in the incoming form data.
 ///
+/// - `alias` => Accept an additional name for the field, e.g. to support
+/// clients still sending a previous name. Can be repeated, and all the names
+/// are treated as the same field when detecting duplicates. Errors are always
+/// reported using the canonical name of the field.
+///
 /// - `default` => Populate the field using the type's [Default] implementation
 /// when the field is not supplied in the request.
 ///
+/// - `limit` => Limit the size of the field data using a human readable unit
+/// (e.g. `"10MiB"`) or `"unlimited"`. A
+/// [FieldTooLarge](crate::TypedMultipartError::FieldTooLarge) error will be
+/// returned when the limit is exceeded.
+///
+/// - `duplicate` => Strategy used when a non-list field is supplied more than
+/// once: `"first"` keeps the first occurrence, `"last"` keeps the last one and
+/// `"reject"` (the default) returns a
+/// [DuplicateField](crate::TypedMultipartError::DuplicateField) error.
+///
+/// - `content_type` => Return an
+/// [UnsupportedContentType](crate::TypedMultipartError::UnsupportedContentType)
+/// error when the `Content-Type` of the field does not match the supplied
+/// pattern, which can contain wildcards (e.g. `"image/*"`). Can be repeated to
+/// allow multiple content types.
+///
+/// - `max_items` / `min_items` => Limit the number of items of a list field,
+/// returning a [TooManyItems](crate::TypedMultipartError::TooManyItems) or a
+/// [TooFewItems](crate::TypedMultipartError::TooFewItems) error.
+///
+/// - `from_str` => Parse the field using the [FromStr](std::str::FromStr)
+/// implementation of its type, like the
+/// [FromStrField](crate::FromStrField) wrapper.
+///
+/// - `checkbox` => Parse a [bool] field leniently using the
+/// [Checkbox](crate::Checkbox) rules, treating a missing field as `false`.
+/// The field cannot be wrapped in an [Option] or a [Vec]. Unless `duplicate`
+/// is set on the field, a checkbox supplied more than once (e.g. after a hidden
+/// input holding the unchecked value) is `true` if any of the values is.
+///
+/// - `format` => Parse the field using the supplied format instead of the
+/// default one. The field type must implement
+/// [TryFromFieldWithFormat](crate::TryFromFieldWithFormat), e.g. the date and
+/// time types of the `chrono` and `time` crates.
+///
+/// - `nested` => Populate the field, which must be a struct deriving
+/// [TryFromMultipart], from the fields named `parent.field` or
+/// `parent[field]`. Use `nested = "dot"` or `nested = "bracket"` to accept
+/// only one of the notations. The field can also be wrapped in an [Option] or
+/// a [Vec], in which case the items are named `parent[0].field`.
+///
 /// ## Example
 ///
 /// ```rust
@@ -0,0 +1,32 @@
+use crate::{FieldPath, TypedMultipartError};
+use axum::async_trait;
+use axum::extract::multipart::Field;
+
+/// Types that can be populated incrementally from the fields of a multipart
+/// request.
+///
+/// The trait is implemented by the [TryFromMultipart](crate::TryFromMultipart)
+/// derive macro, allowing the struct to be nested inside other structs using
+/// the `nested` parameter of the `form_data` attribute. You should not need to
+/// implement it manually.
+#[async_trait]
+pub trait TryFromNestedMultipart: Sized {
+    /// Partial state of the struct while the fields are being received.
+    type State: Default + Send;
+
+    /// Consume the supplied `field` if `name` matches one of the struct
+    /// fields, handing it back otherwise.
+    ///
+    /// The `name` is relative to the struct, while `path` is the location of
+    /// the struct inside the form data.
+    async fn consume_field<'a>(
+        state: &mut Self::State,
+        field: Field<'a>,
+        name: &str,
+        path: FieldPath<'_>,
+    ) -> Result<Option<Field<'a>>, TypedMultipartError>;
+
+    /// Create the struct once all the fields have been received, returning an
+    /// error if a required field is missing.
+    fn from_state(state: Self::State, path: FieldPath<'_>) -> Result<Self, TypedMultipartError>;
+}
@@ -1,14 +1,22 @@
-use crate::{TryFromMultipart, TypedMultipartError};
+use crate::limited_body::LimitedBody;
+use crate::{TempFileConfig, TryFromMultipart, TypedMultipartConfig, TypedMultipartError};
 use axum::body::{Bytes, HttpBody};
 use axum::extract::{FromRequest, Multipart};
-use axum::http::Request;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::{HeaderMap, Request};
 use axum::{async_trait, BoxError};
+use std::sync::{Arc, Mutex};
 
 /// Used as as an argument for [axum handlers](axum::handler::Handler).
 ///
 /// Implements [FromRequest] when the generic argument implements the
 /// [TryFromMultipart] trait.
 ///
+/// If the request contains a [TempFileConfig] extension it will be used to
+/// create the temporary files for the [TempFile](crate::TempFile) fields, while
+/// a [TypedMultipartConfig] extension can be used to limit the size of the
+/// whole request.
+///
 /// ## Example
 ///
 /// ```rust
@@ -41,8 +49,49 @@ where
     type Rejection = TypedMultipartError;
 
     async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
-        let multipart = &mut Multipart::from_request(req, state).await?;
-        let data = T::try_from_multipart(multipart).await?;
-        Ok(Self(data))
+        let config = req.extensions().get::<TypedMultipartConfig>().copied().unwrap_or_default();
+
+        if !config.is_limited() {
+            return Ok(Self(try_from_request(req, state).await?));
+        }
+
+        let error = Arc::new(Mutex::new(None));
+        let boundary = get_boundary(req.headers());
+        let req = req.map(|body| LimitedBody::new(body, config, boundary, error.clone()));
+        let result = try_from_request(req, state).await;
+
+        // When a limit is exceeded the parser only reports a generic error, so
+        // we replace it with the one describing the limit.
+        let limit_error = error.lock().unwrap().take();
+
+        match (result, limit_error) {
+            (Err(_), Some(error)) => Err(error),
+            (result, _) => result.map(Self),
+        }
     }
 }
+
+/// Parse the supplied request using the [TempFileConfig] extension, if any.
+async fn try_from_request<T, S, B>(req: Request<B>, state: &S) -> Result<T, TypedMultipartError>
+where
+    T: TryFromMultipart,
+    B: HttpBody + Send + 'static,
+    B::Data: Into<Bytes>,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+{
+    let config = req.extensions().get::<TempFileConfig>().cloned().unwrap_or_default();
+    let multipart = &mut Multipart::from_request(req, state).await?;
+    config.scope(T::try_from_multipart(multipart)).await
+}
+
+/// Get the boundary parameter from the `Content-Type` header.
+fn get_boundary(headers: &HeaderMap) -> Option<String> {
+    let content_type = headers.get(CONTENT_TYPE)?.to_str().ok()?;
+
+    content_type.split(';').find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        let is_boundary = name.trim().eq_ignore_ascii_case("boundary");
+        is_boundary.then(|| value.trim().trim_matches('"').to_string())
+    })
+}
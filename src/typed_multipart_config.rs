@@ -0,0 +1,82 @@
+/// Limits enforced by the [TypedMultipart](crate::TypedMultipart) extractor on
+/// the whole request.
+///
+/// The configuration can be supplied to the extractor by adding it as an
+/// [Extension](axum::Extension) to the router. All the limits are disabled by
+/// default.
+///
+/// Note that the axum [DefaultBodyLimit](axum::extract::DefaultBodyLimit) is
+/// still applied to the request, so it must be raised or disabled to allow
+/// bodies larger than 2MB.
+///
+/// ## Example
+///
+/// ```rust
+/// use axum::extract::DefaultBodyLimit;
+/// use axum::routing::post;
+/// use axum::{Extension, Router};
+/// use axum_typed_multipart::{TryFromMultipart, TypedMultipart, TypedMultipartConfig};
+///
+/// #[derive(TryFromMultipart)]
+/// struct Foo {
+///     name: String,
+/// }
+///
+/// async fn handler(TypedMultipart(foo): TypedMultipart<Foo>) {
+///     // ...
+/// }
+///
+/// let config = TypedMultipartConfig::new()
+///     .max_body_size(100 * 1024 * 1024)
+///     .max_parts(10)
+///     .max_part_header_size(8 * 1024);
+///
+/// let app: Router = Router::new()
+///     .route("/", post(handler))
+///     .layer(Extension(config))
+///     .layer(DefaultBodyLimit::disable());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TypedMultipartConfig {
+    pub(crate) max_body_size: Option<usize>,
+    pub(crate) max_parts: Option<usize>,
+    pub(crate) max_part_header_size: Option<usize>,
+}
+
+impl TypedMultipartConfig {
+    /// Create a configuration without any limit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum size in bytes of the request body, returning a
+    /// [RequestTooLarge](crate::TypedMultipartError::RequestTooLarge) error
+    /// when exceeded.
+    pub fn max_body_size(mut self, limit_bytes: usize) -> Self {
+        self.max_body_size = Some(limit_bytes);
+        self
+    }
+
+    /// Set the maximum number of parts in the request, returning a
+    /// [TooManyParts](crate::TypedMultipartError::TooManyParts) error when
+    /// exceeded.
+    pub fn max_parts(mut self, limit: usize) -> Self {
+        self.max_parts = Some(limit);
+        self
+    }
+
+    /// Set the maximum size in bytes of the headers of each part, returning a
+    /// [PartHeadersTooLarge](crate::TypedMultipartError::PartHeadersTooLarge)
+    /// error when exceeded.
+    pub fn max_part_header_size(mut self, limit_bytes: usize) -> Self {
+        self.max_part_header_size = Some(limit_bytes);
+        self
+    }
+
+    /// Check if at least one of the limits is enabled.
+    pub(crate) fn is_limited(&self) -> bool {
+        self.max_body_size.is_some()
+            || self.max_parts.is_some()
+            || self.max_part_header_size.is_some()
+    }
+}
@@ -1,6 +1,17 @@
 use axum::extract::multipart::{MultipartError, MultipartRejection};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
+#[cfg(feature = "json")]
+use axum::Json;
+
+/// Error related to a single field of the request, collected in
+/// [TypedMultipartError::Multiple] when the `collect_errors` parameter is
+/// supplied to the `form_data` attribute of the struct.
+#[derive(Debug)]
+pub struct FieldError {
+    pub field_name: String,
+    pub error: TypedMultipartError,
+}
 
 #[derive(thiserror::Error, Debug)]
 pub enum TypedMultipartError {
@@ -19,8 +30,68 @@ pub enum TypedMultipartError {
     #[error("field '{field_name}' is required")]
     MissingField { field_name: String },
 
-    #[error("field '{field_name}' must be of type '{wanted_type}'")]
-    WrongFieldType { field_name: String, wanted_type: String },
+    #[error(
+        "field '{field_name}' must be of type '{wanted_type}'{}",
+        .source.as_ref().map(|source| format!(" ({source})")).unwrap_or_default()
+    )]
+    WrongFieldType {
+        field_name: String,
+        wanted_type: String,
+        #[source]
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
+
+    #[error("field '{field_name}' is larger than {limit_bytes} bytes")]
+    FieldTooLarge { field_name: String, limit_bytes: usize },
+
+    #[error("request is larger than {limit_bytes} bytes")]
+    RequestTooLarge { limit_bytes: usize },
+
+    #[error("request contains more than {limit} parts")]
+    TooManyParts { limit: usize },
+
+    #[error("headers of a part are larger than {limit_bytes} bytes")]
+    PartHeadersTooLarge { limit_bytes: usize },
+
+    #[error("field '{field_name}' contains more than {limit} items")]
+    TooManyItems { field_name: String, limit: usize },
+
+    #[error("field '{field_name}' contains less than {limit} items")]
+    TooFewItems { field_name: String, limit: usize },
+
+    #[error("request contains more than {limit} fields")]
+    TooManyFields { limit: usize },
+
+    #[error("field '{field_name}' is not expected")]
+    UnknownField { field_name: String },
+
+    #[error("field '{field_name}' is supplied more than once")]
+    DuplicateField { field_name: String },
+
+    #[error("field '{field_name}' does not match the supplied {algorithm} checksum")]
+    ChecksumMismatch { field_name: String, algorithm: String },
+
+    #[error(
+        "field '{field_name}' has unsupported content type '{}' (allowed: {})",
+        .got.as_deref().unwrap_or_default(),
+        .allowed.join(", ")
+    )]
+    UnsupportedContentType { field_name: String, got: Option<String>, allowed: Vec<String> },
+
+    #[error("field '{field_name}' is declared as '{declared}' but contains '{detected}'")]
+    ContentTypeMismatch { field_name: String, declared: String, detected: String },
+
+    #[error("request contains a field without a name")]
+    NamelessField,
+
+    #[error("I/O error while processing the request ({source})")]
+    Io {
+        #[from]
+        source: std::io::Error,
+    },
+
+    #[error("{}", .0.iter().map(|e| e.error.to_string()).collect::<Vec<_>>().join(", "))]
+    Multiple(Vec<FieldError>),
 
     #[error(transparent)]
     Other {
@@ -32,16 +103,154 @@ pub enum TypedMultipartError {
 impl TypedMultipartError {
     fn get_status(&self) -> StatusCode {
         match self {
-            Self::Other { .. } => StatusCode::INTERNAL_SERVER_ERROR,
-            Self::MissingField { .. } | Self::WrongFieldType { .. } => StatusCode::BAD_REQUEST,
+            Self::Io { .. } | Self::Other { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::MissingField { .. }
+            | Self::WrongFieldType { .. }
+            | Self::TooManyItems { .. }
+            | Self::TooFewItems { .. }
+            | Self::UnknownField { .. }
+            | Self::DuplicateField { .. }
+            | Self::ChecksumMismatch { .. }
+            | Self::NamelessField
+            | Self::Multiple(_) => StatusCode::BAD_REQUEST,
+            Self::FieldTooLarge { .. }
+            | Self::RequestTooLarge { .. }
+            | Self::TooManyParts { .. }
+            | Self::PartHeadersTooLarge { .. }
+            | Self::TooManyFields { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::UnsupportedContentType { .. } | Self::ContentTypeMismatch { .. } => {
+                StatusCode::UNSUPPORTED_MEDIA_TYPE
+            }
             Self::InvalidRequest { source } => source.status(),
             Self::InvalidRequestBody { source } => source.status(),
         }
     }
+
+    /// Get a stable, machine readable code identifying the kind of error.
+    pub fn get_code(&self) -> &'static str {
+        match self {
+            Self::InvalidRequest { .. } => "invalid_request",
+            Self::InvalidRequestBody { .. } => "invalid_request_body",
+            Self::MissingField { .. } => "missing_field",
+            Self::WrongFieldType { .. } => "wrong_field_type",
+            Self::FieldTooLarge { .. } => "field_too_large",
+            Self::RequestTooLarge { .. } => "request_too_large",
+            Self::TooManyParts { .. } => "too_many_parts",
+            Self::PartHeadersTooLarge { .. } => "part_headers_too_large",
+            Self::TooManyItems { .. } => "too_many_items",
+            Self::TooFewItems { .. } => "too_few_items",
+            Self::TooManyFields { .. } => "too_many_fields",
+            Self::UnknownField { .. } => "unknown_field",
+            Self::DuplicateField { .. } => "duplicate_field",
+            Self::ChecksumMismatch { .. } => "checksum_mismatch",
+            Self::UnsupportedContentType { .. } => "unsupported_content_type",
+            Self::ContentTypeMismatch { .. } => "content_type_mismatch",
+            Self::NamelessField => "nameless_field",
+            Self::Io { .. } => "io_error",
+            Self::Multiple(_) => "multiple_errors",
+            Self::Other { .. } => "internal_error",
+        }
+    }
+
+    /// Get the name of the field that caused the error, if any.
+    pub fn get_field_name(&self) -> Option<&str> {
+        match self {
+            Self::MissingField { field_name }
+            | Self::WrongFieldType { field_name, .. }
+            | Self::FieldTooLarge { field_name, .. }
+            | Self::TooManyItems { field_name, .. }
+            | Self::TooFewItems { field_name, .. }
+            | Self::UnknownField { field_name }
+            | Self::DuplicateField { field_name }
+            | Self::ChecksumMismatch { field_name, .. }
+            | Self::UnsupportedContentType { field_name, .. }
+            | Self::ContentTypeMismatch { field_name, .. } => Some(field_name),
+            _ => None,
+        }
+    }
+
+    /// Replace the name of the field that caused the error, if any.
+    ///
+    /// Used by the derive macro to report the errors of fields supplied using
+    /// an alias under their canonical name.
+    #[doc(hidden)]
+    pub fn with_field_name(mut self, name: String) -> Self {
+        match &mut self {
+            Self::MissingField { field_name }
+            | Self::WrongFieldType { field_name, .. }
+            | Self::FieldTooLarge { field_name, .. }
+            | Self::TooManyItems { field_name, .. }
+            | Self::TooFewItems { field_name, .. }
+            | Self::UnknownField { field_name }
+            | Self::DuplicateField { field_name }
+            | Self::ChecksumMismatch { field_name, .. }
+            | Self::UnsupportedContentType { field_name, .. }
+            | Self::ContentTypeMismatch { field_name, .. } => *field_name = name,
+            _ => {}
+        }
+
+        self
+    }
+
+    /// Convert the error into a response with a JSON body, using the same
+    /// status code as the [IntoResponse] implementation.
+    ///
+    /// ## Example
+    ///
+    /// ```json
+    /// {
+    ///     "error": "wrong_field_type",
+    ///     "field": "age",
+    ///     "message": "field 'age' must be of type 'u8' (invalid digit found in string)",
+    ///     "expected_type": "u8",
+    ///     "reason": "invalid digit found in string"
+    /// }
+    /// ```
+    ///
+    /// The [WrongFieldType](Self::WrongFieldType) variant will additionally
+    /// include a `reason` string when the source of the parse error is known,
+    /// while the [Multiple](Self::Multiple) variant will include an `errors`
+    /// array containing the representation of each error.
+    #[cfg(feature = "json")]
+    pub fn into_json_response(self) -> Response {
+        (self.get_status(), Json(self.to_json())).into_response()
+    }
+
+    #[cfg(feature = "json")]
+    fn to_json(&self) -> serde_json::Value {
+        let expected_type = match self {
+            Self::WrongFieldType { wanted_type, .. } => Some(wanted_type.as_str()),
+            _ => None,
+        };
+
+        let mut json = serde_json::json!({
+            "error": self.get_code(),
+            "field": self.get_field_name(),
+            "message": self.to_string(),
+            "expected_type": expected_type,
+        });
+
+        if let Self::WrongFieldType { source: Some(source), .. } = self {
+            json["reason"] = serde_json::Value::from(source.to_string());
+        }
+
+        if let Self::Multiple(errors) = self {
+            let errors = errors.iter().map(|e| e.error.to_json()).collect();
+            json["errors"] = serde_json::Value::Array(errors);
+        }
+
+        json
+    }
 }
 
 impl IntoResponse for TypedMultipartError {
+    #[cfg(not(feature = "json"))]
     fn into_response(self) -> Response {
         (self.get_status(), self.to_string()).into_response()
     }
+
+    #[cfg(feature = "json")]
+    fn into_response(self) -> Response {
+        self.into_json_response()
+    }
 }
@@ -0,0 +1,126 @@
+use crate::{FieldMetadata, TryFromChunks, TypedMultipartError};
+use axum::async_trait;
+use axum::body::Bytes;
+use axum::http::HeaderMap;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use digest::{Digest, DynDigest};
+use futures_util::stream::{Stream, StreamExt};
+
+/// Wrapper verifying the field data against the checksums supplied by the
+/// client in the headers of the field, returning a
+/// [ChecksumMismatch](crate::TypedMultipartError::ChecksumMismatch) error when
+/// the data does not match.
+///
+/// The checksums are read from the `Content-MD5`, `Digest` and `Repr-Digest`
+/// headers, supporting the MD5, SHA-1, SHA-256 and SHA-512 algorithms. The
+/// checksums using other algorithms are ignored.
+///
+/// ## Example
+///
+/// ```rust
+/// use axum_typed_multipart::{TempFile, TryFromMultipart, Verified};
+///
+/// #[derive(TryFromMultipart)]
+/// struct FileUpload {
+///     file: Verified<TempFile>,
+/// }
+/// ```
+pub struct Verified<T> {
+    pub contents: T,
+    /// Whether the request contained at least one supported checksum.
+    pub verified: bool,
+}
+
+#[async_trait]
+impl<T: TryFromChunks + Send> TryFromChunks for Verified<T> {
+    async fn try_from_chunks(
+        chunks: impl Stream<Item = Result<Bytes, TypedMultipartError>> + Send + Unpin,
+        metadata: FieldMetadata,
+    ) -> Result<Self, TypedMultipartError> {
+        let field_name = metadata.name.clone().unwrap_or_default();
+
+        let mut checksums = get_checksums(&metadata.headers)
+            .into_iter()
+            .filter_map(|(name, value)| Some((name, value, get_hasher(name)?)))
+            .collect::<Vec<_>>();
+
+        let chunks = chunks.map(|chunk| {
+            if let Ok(chunk) = &chunk {
+                for (_, _, hasher) in checksums.iter_mut() {
+                    hasher.update(chunk);
+                }
+            }
+
+            chunk
+        });
+
+        let contents = T::try_from_chunks(chunks, metadata).await?;
+        let verified = !checksums.is_empty();
+
+        for (name, value, hasher) in checksums {
+            let expected = STANDARD.decode(value).ok();
+
+            if expected.as_deref() != Some(&*hasher.finalize()) {
+                return Err(TypedMultipartError::ChecksumMismatch {
+                    field_name,
+                    algorithm: name.to_string(),
+                });
+            }
+        }
+
+        Ok(Self { contents, verified })
+    }
+}
+
+/// Get the supported algorithms and the base64 encoded checksums from the
+/// supplied headers.
+fn get_checksums(headers: &HeaderMap) -> Vec<(&'static str, String)> {
+    let mut checksums = Vec::new();
+
+    for value in headers.get_all("content-md5").iter().filter_map(|v| v.to_str().ok()) {
+        checksums.push(("MD5", value.trim().to_string()));
+    }
+
+    // e.g. `Digest: SHA-256=X48E9q...=, MD5=HUXZ...==`
+    for value in headers.get_all("digest").iter().filter_map(|v| v.to_str().ok()) {
+        for (name, value) in value.split(',').filter_map(|item| item.split_once('=')) {
+            if let Some(name) = get_algorithm_name(name) {
+                checksums.push((name, value.trim().to_string()));
+            }
+        }
+    }
+
+    // e.g. `Repr-Digest: sha-256=:X48E9q...=:`
+    for value in headers.get_all("repr-digest").iter().filter_map(|v| v.to_str().ok()) {
+        for (name, value) in value.split(',').filter_map(|item| item.split_once('=')) {
+            if let Some(name) = get_algorithm_name(name) {
+                checksums.push((name, value.trim().trim_matches(':').to_string()));
+            }
+        }
+    }
+
+    checksums
+}
+
+/// Get the canonical name of the supplied algorithm, if supported.
+fn get_algorithm_name(name: &str) -> Option<&'static str> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "md5" => Some("MD5"),
+        "sha" | "sha-1" => Some("SHA-1"),
+        "sha-256" => Some("SHA-256"),
+        "sha-512" => Some("SHA-512"),
+        _ => None,
+    }
+}
+
+/// Create the hasher for the supplied algorithm.
+fn get_hasher(name: &str) -> Option<Box<dyn DynDigest + Send>> {
+    match name {
+        "MD5" => Some(Box::new(md5::Md5::new())),
+        "SHA-1" => Some(Box::new(sha1::Sha1::new())),
+        "SHA-256" => Some(Box::new(sha2::Sha256::new())),
+        "SHA-512" => Some(Box::new(sha2::Sha512::new())),
+        _ => None,
+    }
+}
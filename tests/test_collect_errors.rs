@@ -0,0 +1,56 @@
+mod util;
+
+use axum::extract::FromRequest;
+use axum_typed_multipart::{TryFromMultipart, TypedMultipart, TypedMultipartError};
+use common_multipart_rfc7578::client::multipart::Form;
+use util::get_request_from_form;
+
+#[derive(TryFromMultipart, Debug)]
+#[form_data(collect_errors, strict)]
+struct Foo {
+    name: String,
+    age: u8,
+    email: String,
+    #[form_data(default)]
+    nickname: String,
+}
+
+#[tokio::test]
+async fn test_collect_errors_valid() {
+    let mut form = Form::default();
+    form.add_text("name", "John");
+    form.add_text("age", "42");
+    form.add_text("email", "john@example.com");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.name, "John");
+    assert_eq!(data.age, 42);
+    assert_eq!(data.email, "john@example.com");
+    assert_eq!(data.nickname, "");
+}
+
+#[tokio::test]
+async fn test_collect_errors() {
+    let mut form = Form::default();
+    form.add_text("name", "John");
+    form.add_text("name", "Jack");
+    form.add_text("age", "hello");
+    form.add_text("other", "42");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap_err();
+
+    let TypedMultipartError::Multiple(errors) = error else {
+        panic!("expected multiple errors, got {error:?}");
+    };
+
+    let errors = errors.iter().map(|e| (e.field_name.as_str(), &e.error)).collect::<Vec<_>>();
+
+    assert!(matches!(errors[0], ("name", TypedMultipartError::DuplicateField { .. })));
+    assert!(matches!(errors[1], ("age", TypedMultipartError::WrongFieldType { .. })));
+    assert!(matches!(errors[2], ("other", TypedMultipartError::UnknownField { .. })));
+    assert!(matches!(errors[3], ("email", TypedMultipartError::MissingField { .. })));
+    assert_eq!(errors.len(), 4);
+}
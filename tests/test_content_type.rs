@@ -0,0 +1,55 @@
+mod util;
+
+use axum::extract::FromRequest;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum_typed_multipart::{TryFromMultipart, TypedMultipart, TypedMultipartError};
+use common_multipart_rfc7578::client::multipart::Form;
+use std::io::BufReader;
+use util::get_request_from_form;
+
+#[derive(TryFromMultipart)]
+struct Foo {
+    #[form_data(content_type = "image/*", content_type = "application/pdf")]
+    file: String,
+    #[form_data(content_type = "text/plain")]
+    text: Option<String>,
+}
+
+fn get_form(mime: mime::Mime) -> Form<'static> {
+    let mut form = Form::default();
+    form.add_reader_file_with_mime("file", BufReader::new("Potato!".as_bytes()), "potato", mime);
+    form
+}
+
+#[tokio::test]
+async fn test_content_type() {
+    for mime in [mime::IMAGE_PNG, mime::IMAGE_JPEG, mime::APPLICATION_PDF] {
+        let mut form = get_form(mime);
+        form.add_text("text", "Tomato!");
+
+        let request = get_request_from_form(form).await;
+        let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+        assert_eq!(data.file, "Potato!");
+        assert_eq!(data.text.unwrap(), "Tomato!");
+    }
+}
+
+#[tokio::test]
+async fn test_unsupported_content_type() {
+    let request = get_request_from_form(get_form(mime::TEXT_HTML)).await;
+    let error = TypedMultipart::<Foo>::from_request(request, &()).await.err().unwrap();
+
+    assert_eq!(
+        error.to_string(),
+        "field 'file' has unsupported content type 'text/html' (allowed: image/*, application/pdf)"
+    );
+
+    assert!(matches!(
+        &error,
+        TypedMultipartError::UnsupportedContentType { got: Some(got), .. } if got == "text/html"
+    ));
+
+    assert_eq!(error.into_response().status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
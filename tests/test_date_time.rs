@@ -0,0 +1,129 @@
+#![cfg(all(feature = "chrono", feature = "time"))]
+
+mod util;
+
+use axum::extract::FromRequest;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum_typed_multipart::{TryFromMultipart, TypedMultipart, TypedMultipartError};
+use common_multipart_rfc7578::client::multipart::Form;
+use std::error::Error;
+use util::get_request_from_form;
+
+#[derive(TryFromMultipart, Debug)]
+struct Foo {
+    date: chrono::NaiveDate,
+    time: chrono::NaiveTime,
+    date_time: chrono::NaiveDateTime,
+    timestamp: chrono::DateTime<chrono::Utc>,
+    #[form_data(format = "%d/%m/%Y")]
+    birthday: chrono::NaiveDate,
+}
+
+#[tokio::test]
+async fn test_chrono() {
+    let mut form = Form::default();
+    form.add_text("date", "2023-04-01");
+    form.add_text("time", "12:30");
+    form.add_text("date_time", "2023-04-01T12:30:15");
+    form.add_text("timestamp", "2023-04-01T12:30:00+02:00");
+    form.add_text("birthday", "25/12/1990");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.date.to_string(), "2023-04-01");
+    assert_eq!(data.time.to_string(), "12:30:00");
+    assert_eq!(data.date_time.to_string(), "2023-04-01 12:30:15");
+    assert_eq!(data.timestamp.to_rfc3339(), "2023-04-01T10:30:00+00:00");
+    assert_eq!(data.birthday.to_string(), "1990-12-25");
+}
+
+#[derive(TryFromMultipart, Debug)]
+struct Bar {
+    date: time::Date,
+    time: time::Time,
+    date_time: time::PrimitiveDateTime,
+    timestamp: time::OffsetDateTime,
+    #[form_data(format = "[day]/[month]/[year]")]
+    birthday: Option<time::Date>,
+}
+
+#[tokio::test]
+async fn test_time() {
+    let mut form = Form::default();
+    form.add_text("date", "2023-04-01");
+    form.add_text("time", "12:30");
+    form.add_text("date_time", "2023-04-01T12:30:15");
+    form.add_text("timestamp", "2023-04-01T12:30:00+02:00");
+    form.add_text("birthday", "25/12/1990");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Bar>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.date.to_string(), "2023-04-01");
+    assert_eq!(data.time.to_string(), "12:30:00.0");
+    assert_eq!(data.date_time.to_string(), "2023-04-01 12:30:15.0");
+    assert_eq!(data.timestamp.offset().whole_hours(), 2);
+    assert_eq!(data.birthday.unwrap().to_string(), "1990-12-25");
+}
+
+#[tokio::test]
+async fn test_wrong_format() {
+    let mut form = Form::default();
+    form.add_text("date", "2023-04-01");
+    form.add_text("time", "12:30");
+    form.add_text("date_time", "2023-04-01T12:30:15");
+    form.add_text("timestamp", "2023-04-01T12:30:00+02:00");
+    form.add_text("birthday", "1990-12-25");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap_err();
+
+    assert!(error.source().unwrap().downcast_ref::<chrono::ParseError>().is_some());
+    assert!(matches!(
+        error,
+        TypedMultipartError::WrongFieldType { field_name, wanted_type, .. }
+            if field_name == "birthday" && wanted_type == "NaiveDate (%d/%m/%Y)"
+    ));
+}
+
+#[tokio::test]
+async fn test_wrong_default_format() {
+    let mut form = Form::default();
+    form.add_text("date", "01/04/2023");
+    form.add_text("time", "12:30");
+    form.add_text("date_time", "2023-04-01T12:30:15");
+    form.add_text("timestamp", "2023-04-01T12:30:00+02:00");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Bar>::from_request(request, &()).await.unwrap_err();
+
+    assert_eq!(error.get_field_name(), Some("date"));
+    assert!(error.source().unwrap().downcast_ref::<time::error::Parse>().is_some());
+    assert!(error.to_string().starts_with("field 'date' must be of type 'Date' ("));
+}
+
+#[derive(TryFromMultipart, Debug)]
+struct Baz {
+    #[form_data(format = "%Q")]
+    #[allow(dead_code)]
+    chrono_date: Option<chrono::NaiveDate>,
+    #[form_data(format = "[day")]
+    #[allow(dead_code)]
+    time_date: Option<time::Date>,
+}
+
+#[tokio::test]
+async fn test_invalid_format() {
+    for name in ["chrono_date", "time_date"] {
+        let mut form = Form::default();
+        form.add_text(name, "25/12/1990");
+
+        let request = get_request_from_form(form).await;
+        let error = TypedMultipart::<Baz>::from_request(request, &()).await.unwrap_err();
+
+        assert!(matches!(error, TypedMultipartError::Other { .. }));
+        assert_eq!(error.into_response().status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,66 @@
+mod util;
+
+use axum::extract::FromRequest;
+use axum_typed_multipart::{TryFromMultipart, TypedMultipart, TypedMultipartError};
+use common_multipart_rfc7578::client::multipart::Form;
+use util::get_request_from_form;
+
+#[derive(TryFromMultipart, Debug)]
+struct Foo {
+    #[form_data(duplicate = "first")]
+    first: String,
+    #[form_data(duplicate = "last")]
+    last: String,
+    reject: Option<String>,
+}
+
+#[derive(TryFromMultipart, Debug)]
+#[form_data(duplicate = "last")]
+struct Bar {
+    name: String,
+}
+
+#[tokio::test]
+async fn test_duplicate_policy() {
+    let mut form = Form::default();
+    form.add_text("first", "A");
+    form.add_text("first", "B");
+    form.add_text("last", "A");
+    form.add_text("last", "B");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.first, "A");
+    assert_eq!(data.last, "B");
+    assert_eq!(data.reject, None);
+}
+
+#[tokio::test]
+async fn test_duplicate_reject() {
+    let mut form = Form::default();
+    form.add_text("first", "A");
+    form.add_text("last", "A");
+    form.add_text("reject", "A");
+    form.add_text("reject", "B");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        TypedMultipartError::DuplicateField { field_name } if field_name == "reject"
+    ));
+}
+
+#[tokio::test]
+async fn test_duplicate_struct_policy() {
+    let mut form = Form::default();
+    form.add_text("name", "A");
+    form.add_text("name", "B");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Bar>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.name, "B");
+}
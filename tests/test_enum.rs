@@ -0,0 +1,65 @@
+mod util;
+
+use axum::extract::FromRequest;
+use axum_typed_multipart::{TryFromField, TryFromMultipart, TypedMultipart, TypedMultipartError};
+use common_multipart_rfc7578::client::multipart::Form;
+use util::get_request_from_form;
+
+#[derive(TryFromField, Debug, PartialEq)]
+enum Sort {
+    Asc,
+    Desc,
+}
+
+#[derive(TryFromField, Debug, PartialEq)]
+#[form_data(rename_all = "kebab-case")]
+enum Color {
+    Red,
+    DarkGreen,
+    #[form_data(rename = "azure")]
+    Blue,
+}
+
+#[derive(TryFromField, Debug, PartialEq)]
+#[form_data(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Method {
+    HttpGet,
+    HTTPPost,
+}
+
+#[derive(TryFromMultipart, Debug)]
+struct Foo {
+    sort: Sort,
+    colors: Vec<Color>,
+    method: Option<Method>,
+}
+
+#[tokio::test]
+async fn test_enum() {
+    let mut form = Form::default();
+    form.add_text("sort", "Desc");
+    form.add_text("colors", "red");
+    form.add_text("colors", "dark-green");
+    form.add_text("colors", "azure");
+    form.add_text("method", "H_T_T_P_POST");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.sort, Sort::Desc);
+    assert_eq!(data.colors, vec![Color::Red, Color::DarkGreen, Color::Blue]);
+    assert_eq!(data.method, Some(Method::HTTPPost));
+}
+
+#[tokio::test]
+async fn test_enum_wrong_value() {
+    let mut form = Form::default();
+    form.add_text("sort", "Asc");
+    form.add_text("colors", "Blue");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap_err();
+
+    assert_eq!(error.to_string(), "field 'colors' must be of type 'red | dark-green | azure'");
+    assert!(matches!(error, TypedMultipartError::WrongFieldType { .. }));
+}
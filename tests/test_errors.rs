@@ -5,6 +5,7 @@ use axum::http::header::CONTENT_TYPE;
 use axum::http::Request;
 use axum_typed_multipart::{TryFromMultipart, TypedMultipart, TypedMultipartError};
 use common_multipart_rfc7578::client::multipart::Form;
+use std::error::Error;
 use util::get_request_from_form;
 
 #[derive(TryFromMultipart, Debug)]
@@ -13,6 +14,33 @@ struct Foo {
     field: u8,
 }
 
+#[derive(TryFromMultipart, Debug)]
+#[form_data(skip_nameless)]
+struct Bar {
+    field: u8,
+}
+
+/// Request containing a field without a name in the `Content-Disposition`
+/// header followed by a valid field.
+fn get_nameless_request() -> Request<String> {
+    let body = concat!(
+        "--BOUNDARY\r\n",
+        "Content-Disposition: form-data\r\n\r\n",
+        "hello\r\n",
+        "--BOUNDARY\r\n",
+        "Content-Disposition: form-data; name=\"field\"\r\n\r\n",
+        "42\r\n",
+        "--BOUNDARY--\r\n",
+    );
+
+    Request::builder()
+        .uri("https://www.rust-lang.org/")
+        .method("POST")
+        .header(CONTENT_TYPE, "multipart/form-data; boundary=BOUNDARY")
+        .body(String::from(body))
+        .unwrap()
+}
+
 #[tokio::test]
 async fn test_invalid_request() {
     let mut form = Form::default();
@@ -67,4 +95,27 @@ async fn test_wrong_field_type() {
     let error = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap_err();
 
     assert!(matches!(error, TypedMultipartError::WrongFieldType { .. }));
+    assert_eq!(
+        error.to_string(),
+        "field 'field' must be of type 'u8' (invalid digit found in string)"
+    );
+
+    let source = error.source().unwrap();
+    assert!(source.downcast_ref::<std::num::ParseIntError>().is_some());
+}
+
+#[tokio::test]
+async fn test_nameless_field() {
+    let request = get_nameless_request();
+    let error = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap_err();
+
+    assert!(matches!(error, TypedMultipartError::NamelessField));
+}
+
+#[tokio::test]
+async fn test_nameless_field_skipped() {
+    let request = get_nameless_request();
+    let data = TypedMultipart::<Bar>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.field, 42);
 }
@@ -0,0 +1,105 @@
+mod util;
+
+use axum::extract::FromRequest;
+use axum_typed_multipart::{FromStrField, TryFromMultipart, TypedMultipart, TypedMultipartError};
+use common_multipart_rfc7578::client::multipart::Form;
+use std::str::FromStr;
+use util::get_request_from_form;
+
+#[derive(Debug, PartialEq)]
+struct Sku(String);
+
+impl FromStr for Sku {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("SKU-") {
+            Some(code) => Ok(Sku(code.to_string())),
+            None => Err("missing SKU- prefix"),
+        }
+    }
+}
+
+#[derive(TryFromMultipart, Debug)]
+struct Foo {
+    sku: FromStrField<Sku>,
+    #[form_data(from_str)]
+    replacements: Vec<Sku>,
+    #[form_data(from_str)]
+    parent: Option<Sku>,
+}
+
+#[tokio::test]
+async fn test_from_str() {
+    let mut form = Form::default();
+    form.add_text("sku", "SKU-1");
+    form.add_text("replacements", "SKU-2");
+    form.add_text("replacements", "SKU-3");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.sku.0, Sku(String::from("1")));
+    assert_eq!(data.replacements, vec![Sku(String::from("2")), Sku(String::from("3"))]);
+    assert_eq!(data.parent, None);
+}
+
+async fn get_error(fields: &[(&str, &str)]) -> TypedMultipartError {
+    let mut form = Form::default();
+
+    for (name, value) in fields {
+        form.add_text(*name, *value);
+    }
+
+    let request = get_request_from_form(form).await;
+    TypedMultipart::<Foo>::from_request(request, &()).await.unwrap_err()
+}
+
+#[tokio::test]
+async fn test_from_str_source() {
+    let error = get_error(&[("sku", "1")]).await;
+
+    assert_eq!(error.to_string(), "field 'sku' must be of type 'Sku' (missing SKU- prefix)");
+    assert!(matches!(
+        error,
+        TypedMultipartError::WrongFieldType { source: Some(source), .. }
+            if source.to_string() == "missing SKU- prefix"
+    ));
+
+    let error = get_error(&[("sku", "SKU-1"), ("parent", "1")]).await;
+
+    assert_eq!(error.get_field_name(), Some("parent"));
+    assert!(matches!(error, TypedMultipartError::WrongFieldType { source: Some(_), .. }));
+}
+
+#[derive(Debug)]
+struct Tagged<T>(T);
+
+impl<T: FromStr> FromStr for Tagged<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.strip_prefix('#').unwrap_or(s).parse().map(Tagged)
+    }
+}
+
+#[derive(TryFromMultipart, Debug)]
+struct Bar {
+    #[form_data(from_str)]
+    #[allow(dead_code)]
+    tag: Tagged<Sku>,
+}
+
+#[tokio::test]
+async fn test_from_str_generic_type_name() {
+    let mut form = Form::default();
+    form.add_text("tag", "#1");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Bar>::from_request(request, &()).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        TypedMultipartError::WrongFieldType { wanted_type, .. } if wanted_type == "Tagged<Sku>"
+    ));
+}
@@ -0,0 +1,37 @@
+mod util;
+
+use axum::extract::FromRequest;
+use axum_typed_multipart::{FieldData, TryFromField, TryFromMultipart, TypedMultipart};
+use common_multipart_rfc7578::client::multipart::Form;
+use util::get_request_from_form;
+
+#[derive(TryFromMultipart)]
+struct Foo<M: TryFromField, T>
+where
+    T: Default,
+{
+    meta: M,
+    file: FieldData<String>,
+    tags: Vec<T>,
+    note: Option<T>,
+    #[form_data(default)]
+    extra: T,
+}
+
+#[tokio::test]
+async fn test_generics() {
+    let mut form = Form::default();
+    form.add_text("meta", "42");
+    form.add_text("file", "Potato!");
+    form.add_text("tags", "1");
+    form.add_text("tags", "2");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo<u8, u16>>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.meta, 42);
+    assert_eq!(data.file.contents, "Potato!");
+    assert_eq!(data.tags, vec![1, 2]);
+    assert_eq!(data.note, None);
+    assert_eq!(data.extra, 0);
+}
@@ -0,0 +1,49 @@
+#![cfg(all(feature = "sha2", feature = "sha1", feature = "md5", feature = "blake3"))]
+
+mod util;
+
+use axum::extract::FromRequest;
+use axum_typed_multipart::{
+    Blake3, Hashed, Md5, Sha1, Sha256, TempFile, TryFromMultipart, TypedMultipart,
+};
+use common_multipart_rfc7578::client::multipart::Form;
+use std::fs::read_to_string;
+use std::io::BufReader;
+use util::get_request_from_form;
+
+#[derive(TryFromMultipart)]
+struct Foo {
+    file: Hashed<Hashed<TempFile, Sha256>, Md5>,
+    text: Hashed<String, Sha1>,
+    data: Hashed<String, Blake3>,
+}
+
+#[tokio::test]
+async fn test_hashed() {
+    let mut form = Form::default();
+
+    form.add_reader_file_with_mime(
+        "file",
+        BufReader::new("Potato!".as_bytes()),
+        "potato.txt",
+        mime::TEXT_PLAIN,
+    );
+
+    form.add_text("text", "Potato!");
+    form.add_text("data", "Potato!");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    let expected = "bf82efa58491263cf5c3c8866f7f20ec18d25f2c0894b323184a7625ed529084";
+    assert_eq!(data.file.contents.hex_digest(), expected);
+    assert_eq!(data.file.hex_digest(), "c24e419ca82787bd6cac44d786bd5e55");
+    assert_eq!(read_to_string(data.file.contents.contents.path()).unwrap(), "Potato!");
+
+    assert_eq!(data.text.contents, "Potato!");
+    assert_eq!(data.text.hex_digest(), "229421eaceb36d731f248d64d0f0b4b4f273d9ac");
+
+    let expected = "7776fabf3ed6ab1d75073e6a7c5c00e548d302a4621dfc7fb4d56fa822d1b218";
+    assert_eq!(data.data.contents, "Potato!");
+    assert_eq!(data.data.hex_digest(), expected);
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "json")]
+
+mod util;
+
+use axum::body::HttpBody;
+use axum::extract::FromRequest;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum_typed_multipart::{TryFromMultipart, TypedMultipart};
+use common_multipart_rfc7578::client::multipart::Form;
+use serde_json::{json, Value};
+use util::get_request_from_form;
+
+#[derive(TryFromMultipart, Debug)]
+struct Foo {
+    #[allow(dead_code)]
+    field: u8,
+}
+
+#[tokio::test]
+async fn test_json_response() {
+    let mut form = Form::default();
+    form.add_text("field", "hello");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap_err();
+
+    let mut response = error.into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = response.body_mut().data().await.unwrap().unwrap();
+    let body = serde_json::from_slice::<Value>(&body).unwrap();
+
+    assert_eq!(
+        body,
+        json!({
+            "error": "wrong_field_type",
+            "field": "field",
+            "message": "field 'field' must be of type 'u8' (invalid digit found in string)",
+            "expected_type": "u8",
+            "reason": "invalid digit found in string",
+        })
+    );
+}
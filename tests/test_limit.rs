@@ -0,0 +1,56 @@
+mod util;
+
+use axum::body::Bytes;
+use axum::extract::FromRequest;
+use axum_typed_multipart::{TempFile, TryFromMultipart, TypedMultipart, TypedMultipartError};
+use common_multipart_rfc7578::client::multipart::Form;
+use util::get_request_from_form;
+
+#[derive(TryFromMultipart)]
+struct Foo {
+    #[form_data(limit = "8B")]
+    text: String,
+    #[form_data(limit = "8B")]
+    bytes: Bytes,
+    #[allow(dead_code)]
+    #[form_data(limit = "8B")]
+    file: TempFile,
+    #[form_data(limit = "unlimited")]
+    other: String,
+}
+
+fn get_form(text: &str, bytes: &str, file: &str) -> Form<'static> {
+    let mut form = Form::default();
+    form.add_text("text", text.to_string());
+    form.add_text("bytes", bytes.to_string());
+    form.add_text("file", file.to_string());
+    form.add_text("other", "x".repeat(1024));
+    form
+}
+
+#[tokio::test]
+async fn test_limit() {
+    let request = get_request_from_form(get_form("12345678", "12345678", "12345678")).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.text, "12345678");
+    assert_eq!(data.bytes, "12345678");
+    assert_eq!(data.other.len(), 1024);
+}
+
+#[tokio::test]
+async fn test_limit_exceeded() {
+    for (form, field) in [
+        (get_form("123456789", "", ""), "text"),
+        (get_form("", "123456789", ""), "bytes"),
+        (get_form("", "", "123456789"), "file"),
+    ] {
+        let request = get_request_from_form(form).await;
+        let error = TypedMultipart::<Foo>::from_request(request, &()).await.err().unwrap();
+
+        assert!(matches!(
+            error,
+            TypedMultipartError::FieldTooLarge { field_name, limit_bytes: 8 } if field_name == field
+        ));
+    }
+}
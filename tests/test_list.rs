@@ -1,7 +1,7 @@
 mod util;
 
 use axum::extract::FromRequest;
-use axum_typed_multipart::{TryFromMultipart, TypedMultipart};
+use axum_typed_multipart::{TryFromMultipart, TypedMultipart, TypedMultipartError};
 use common_multipart_rfc7578::client::multipart::Form;
 use util::get_request_from_form;
 
@@ -25,3 +25,46 @@ async fn test_field_data() {
     assert_eq!(data.items, vec![String::from("bread"), String::from("cheese")]);
     assert_eq!(data.names, Vec::<String>::new());
 }
+
+#[derive(TryFromMultipart, Debug)]
+#[form_data(max_fields = 4)]
+struct Bar {
+    #[form_data(min_items = 1, max_items = 2)]
+    #[allow(dead_code)]
+    items: Vec<u8>,
+}
+
+async fn get_bar(items: &[&str], others: usize) -> Result<Bar, TypedMultipartError> {
+    let mut form = Form::default();
+
+    for item in items {
+        form.add_text("items", *item);
+    }
+
+    for _ in 0..others {
+        form.add_text("other", "Potato!");
+    }
+
+    let request = get_request_from_form(form).await;
+    TypedMultipart::<Bar>::from_request(request, &()).await.map(|data| data.0)
+}
+
+#[tokio::test]
+async fn test_list_limits() {
+    assert!(get_bar(&["1", "2"], 2).await.is_ok());
+
+    let error = get_bar(&["1", "2", "3"], 0).await.unwrap_err();
+    assert_eq!(error.to_string(), "field 'items' contains more than 2 items");
+    assert!(matches!(error, TypedMultipartError::TooManyItems { limit: 2, .. }));
+
+    let error = get_bar(&[], 1).await.unwrap_err();
+    assert_eq!(error.to_string(), "field 'items' contains less than 1 items");
+    assert!(matches!(error, TypedMultipartError::TooFewItems { limit: 1, .. }));
+}
+
+#[tokio::test]
+async fn test_max_fields() {
+    let error = get_bar(&["1", "2"], 3).await.unwrap_err();
+
+    assert!(matches!(error, TypedMultipartError::TooManyFields { limit: 4 }));
+}
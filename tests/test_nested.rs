@@ -0,0 +1,133 @@
+mod util;
+
+use axum::extract::FromRequest;
+use axum_typed_multipart::{TryFromMultipart, TypedMultipart, TypedMultipartError};
+use common_multipart_rfc7578::client::multipart::Form;
+use util::get_request_from_form;
+
+#[derive(TryFromMultipart, Debug)]
+struct Address {
+    street: String,
+    city: String,
+    zip: Option<u32>,
+}
+
+#[derive(TryFromMultipart, Debug)]
+struct Item {
+    name: String,
+    quantity: u32,
+}
+
+#[derive(TryFromMultipart, Debug)]
+struct Foo {
+    name: String,
+    #[form_data(nested)]
+    address: Address,
+    #[form_data(nested)]
+    billing: Option<Address>,
+    #[form_data(nested)]
+    items: Vec<Item>,
+}
+
+#[derive(TryFromMultipart, Debug)]
+#[form_data(strict)]
+struct Bar {
+    #[form_data(nested = "bracket")]
+    #[allow(dead_code)]
+    address: Address,
+}
+
+#[tokio::test]
+async fn test_nested() {
+    let mut form = Form::default();
+    form.add_text("name", "John");
+    form.add_text("address.street", "Main Street");
+    form.add_text("address[city]", "Springfield");
+    form.add_text("items[1][name]", "Potato");
+    form.add_text("items[1][quantity]", "2");
+    form.add_text("items[0].name", "Tomato");
+    form.add_text("items[0].quantity", "5");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.name, "John");
+    assert_eq!(data.address.street, "Main Street");
+    assert_eq!(data.address.city, "Springfield");
+    assert_eq!(data.address.zip, None);
+    assert!(data.billing.is_none());
+    assert_eq!(data.items.len(), 2);
+    assert_eq!(data.items[0].name, "Tomato");
+    assert_eq!(data.items[0].quantity, 5);
+    assert_eq!(data.items[1].name, "Potato");
+    assert_eq!(data.items[1].quantity, 2);
+}
+
+#[tokio::test]
+async fn test_nested_optional() {
+    let mut form = Form::default();
+    form.add_text("name", "John");
+    form.add_text("address.street", "Main Street");
+    form.add_text("address.city", "Springfield");
+    form.add_text("billing.street", "Side Street");
+    form.add_text("billing.city", "Shelbyville");
+    form.add_text("billing.zip", "12345");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    let billing = data.billing.unwrap();
+    assert_eq!(billing.street, "Side Street");
+    assert_eq!(billing.city, "Shelbyville");
+    assert_eq!(billing.zip, Some(12345));
+    assert!(data.items.is_empty());
+}
+
+#[tokio::test]
+async fn test_nested_missing_field() {
+    let mut form = Form::default();
+    form.add_text("name", "John");
+    form.add_text("address.street", "Main Street");
+    form.add_text("address.city", "Springfield");
+    form.add_text("items[0][name]", "Potato");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        TypedMultipartError::MissingField { field_name } if field_name == "items[0][quantity]"
+    ));
+}
+
+#[tokio::test]
+async fn test_nested_wrong_type() {
+    let mut form = Form::default();
+    form.add_text("name", "John");
+    form.add_text("address[street]", "Main Street");
+    form.add_text("address[city]", "Springfield");
+    form.add_text("address[zip]", "unknown");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        TypedMultipartError::WrongFieldType { field_name, .. } if field_name == "address[zip]"
+    ));
+}
+
+#[tokio::test]
+async fn test_nested_notation() {
+    let mut form = Form::default();
+    form.add_text("address[street]", "Main Street");
+    form.add_text("address.city", "Springfield");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Bar>::from_request(request, &()).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        TypedMultipartError::UnknownField { field_name } if field_name == "address.city"
+    ));
+}
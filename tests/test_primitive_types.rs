@@ -1,8 +1,10 @@
 mod util;
 
-use axum::body::Bytes;
+use axum::body::{Bytes, Full};
 use axum::extract::FromRequest;
-use axum_typed_multipart::{TryFromMultipart, TypedMultipart};
+use axum::http::header::CONTENT_TYPE;
+use axum::http::Request;
+use axum_typed_multipart::{Checkbox, TryFromMultipart, TypedMultipart, TypedMultipartError};
 use common_multipart_rfc7578::client::multipart::Form;
 use util::get_request_from_form;
 
@@ -72,3 +74,164 @@ async fn test_primitive_types() {
     assert_eq!(data.string_field, "Hello, world!");
     assert_eq!(data.bytes_field, "123");
 }
+
+#[derive(TryFromMultipart, Debug)]
+struct Bar {
+    non_zero_field: std::num::NonZeroU32,
+    ip_field: std::net::IpAddr,
+    socket_field: std::net::SocketAddr,
+    path_field: std::path::PathBuf,
+}
+
+#[tokio::test]
+async fn test_std_types() {
+    let mut form = Form::default();
+    form.add_text("non_zero_field", "42");
+    form.add_text("ip_field", "::1");
+    form.add_text("socket_field", "127.0.0.1:8080");
+    form.add_text("path_field", "/tmp/potato.txt");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Bar>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.non_zero_field.get(), 42);
+    assert_eq!(data.ip_field, std::net::Ipv6Addr::LOCALHOST);
+    assert_eq!(data.socket_field.port(), 8080);
+    assert_eq!(data.path_field, std::path::Path::new("/tmp/potato.txt"));
+}
+
+#[tokio::test]
+async fn test_std_types_wanted_type() {
+    for (name, value, wanted) in [
+        ("non_zero_field", "0", "NonZeroU32"),
+        ("ip_field", "localhost", "IpAddr"),
+    ] {
+        let mut form = Form::default();
+
+        for (field, valid) in [
+            ("non_zero_field", "42"),
+            ("ip_field", "::1"),
+            ("socket_field", "127.0.0.1:8080"),
+            ("path_field", "/tmp/potato.txt"),
+        ] {
+            form.add_text(field, if field == name { value } else { valid });
+        }
+
+        let request = get_request_from_form(form).await;
+        let error = TypedMultipart::<Bar>::from_request(request, &()).await.unwrap_err();
+
+        assert!(matches!(
+            error,
+            TypedMultipartError::WrongFieldType { wanted_type, .. } if wanted_type == wanted
+        ));
+    }
+}
+
+#[cfg(all(feature = "rust_decimal", feature = "semver", feature = "url", feature = "uuid"))]
+#[derive(TryFromMultipart)]
+struct Baz {
+    decimal_field: rust_decimal::Decimal,
+    version_field: semver::Version,
+    url_field: url::Url,
+    uuid_field: uuid::Uuid,
+}
+
+#[cfg(all(feature = "rust_decimal", feature = "semver", feature = "url", feature = "uuid"))]
+#[tokio::test]
+async fn test_external_types() {
+    let mut form = Form::default();
+    form.add_text("decimal_field", "42.50");
+    form.add_text("version_field", "1.2.3");
+    form.add_text("url_field", "https://example.com/potato");
+    form.add_text("uuid_field", "67e55044-10b1-426f-9247-bb680e5fe0c8");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Baz>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.decimal_field.to_string(), "42.50");
+    assert_eq!(data.version_field, semver::Version::new(1, 2, 3));
+    assert_eq!(data.url_field.path(), "/potato");
+    assert_eq!(data.uuid_field.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+}
+
+#[derive(TryFromMultipart, Debug)]
+struct Qux {
+    #[form_data(checkbox)]
+    checkbox_field: bool,
+    #[form_data(default)]
+    checkbox_type_field: Checkbox,
+}
+
+async fn get_qux(fields: &[(&str, &str)]) -> Result<Qux, TypedMultipartError> {
+    let mut form = Form::default();
+    form.add_text("other_field", "42");
+
+    for (name, value) in fields {
+        form.add_text(*name, *value);
+    }
+
+    let request = get_request_from_form(form).await;
+    TypedMultipart::<Qux>::from_request(request, &()).await.map(|data| data.0)
+}
+
+#[tokio::test]
+async fn test_checkbox() {
+    for value in ["on", "1", "yes", "true", "ON", "Yes", "TRUE", "newsletter", ""] {
+        let data = get_qux(&[("checkbox_field", value), ("checkbox_type_field", value)]).await;
+        let data = data.unwrap();
+
+        assert!(data.checkbox_field);
+        assert_eq!(data.checkbox_type_field, Checkbox(true));
+    }
+
+    for value in ["off", "0", "no", "false", "OFF", "False"] {
+        let data = get_qux(&[("checkbox_field", value), ("checkbox_type_field", value)]).await;
+        let data = data.unwrap();
+
+        assert!(!data.checkbox_field);
+        assert_eq!(data.checkbox_type_field, Checkbox(false));
+    }
+
+    let data = get_qux(&[]).await.unwrap();
+    assert!(!data.checkbox_field);
+    assert_eq!(data.checkbox_type_field, Checkbox(false));
+
+    // A hidden input supplying the unchecked value precedes the checkbox.
+    let data = get_qux(&[("checkbox_field", "0"), ("checkbox_field", "on")]).await.unwrap();
+    assert!(data.checkbox_field);
+
+    let data = get_qux(&[("checkbox_field", "0")]).await.unwrap();
+    assert!(!data.checkbox_field);
+}
+
+#[derive(TryFromMultipart)]
+struct Text {
+    text: String,
+}
+
+#[tokio::test]
+async fn test_string_charset() {
+    for (content_type, bytes, expected) in [
+        ("text/plain; charset=iso-8859-1", &b"caf\xe9"[..], "café"),
+        ("text/plain; charset=\"UTF-8\"", "café".as_bytes(), "café"),
+        ("text/plain", &b"caf\xe9"[..], "caf\u{FFFD}"),
+    ] {
+        let body = [
+            &b"--BOUNDARY\r\nContent-Disposition: form-data; name=\"text\"\r\n"[..],
+            format!("Content-Type: {content_type}\r\n\r\n").as_bytes(),
+            bytes,
+            b"\r\n--BOUNDARY--\r\n",
+        ]
+        .concat();
+
+        let request = Request::builder()
+            .uri("https://www.rust-lang.org/")
+            .method("POST")
+            .header(CONTENT_TYPE, "multipart/form-data; boundary=BOUNDARY")
+            .body(Full::new(Bytes::from(body)))
+            .unwrap();
+
+        let data = TypedMultipart::<Text>::from_request(request, &()).await.unwrap().0;
+        assert_eq!(data.text, expected);
+    }
+}
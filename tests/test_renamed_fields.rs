@@ -1,7 +1,7 @@
 mod util;
 
 use axum::extract::FromRequest;
-use axum_typed_multipart::{TryFromMultipart, TypedMultipart};
+use axum_typed_multipart::{TryFromMultipart, TypedMultipart, TypedMultipartError};
 use common_multipart_rfc7578::client::multipart::Form;
 use util::get_request_from_form;
 
@@ -21,3 +21,107 @@ async fn test_renamed_field() {
 
     assert_eq!(data.field, 42);
 }
+
+#[derive(TryFromMultipart)]
+#[form_data(rename_all = "camelCase")]
+struct Bar {
+    first_name: String,
+    r#type: u8,
+    #[form_data(field_name = "surname")]
+    last_name: String,
+}
+
+#[derive(TryFromMultipart)]
+#[form_data(rename_all = "kebab-case")]
+struct Baz {
+    first_name: String,
+    zip_code_v2: u32,
+}
+
+#[tokio::test]
+async fn test_rename_all() {
+    let mut form = Form::default();
+    form.add_text("firstName", "John");
+    form.add_text("type", "1");
+    form.add_text("surname", "Doe");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Bar>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.first_name, "John");
+    assert_eq!(data.r#type, 1);
+    assert_eq!(data.last_name, "Doe");
+}
+
+#[tokio::test]
+async fn test_rename_all_kebab_case() {
+    let mut form = Form::default();
+    form.add_text("first-name", "John");
+    form.add_text("zip-code-v2", "12345");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Baz>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.first_name, "John");
+    assert_eq!(data.zip_code_v2, 12345);
+}
+
+#[derive(TryFromMultipart, Debug)]
+struct Qux {
+    #[form_data(field_name = "name", alias = "username", alias = "login")]
+    field: String,
+}
+
+#[tokio::test]
+async fn test_alias() {
+    for name in ["name", "username", "login"] {
+        let mut form = Form::default();
+        form.add_text(name, "John");
+
+        let request = get_request_from_form(form).await;
+        let data = TypedMultipart::<Qux>::from_request(request, &()).await.unwrap().0;
+
+        assert_eq!(data.field, "John");
+    }
+}
+
+#[tokio::test]
+async fn test_alias_duplicate() {
+    let mut form = Form::default();
+    form.add_text("username", "John");
+    form.add_text("login", "Jane");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Qux>::from_request(request, &()).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        TypedMultipartError::DuplicateField { field_name } if field_name == "name"
+    ));
+}
+
+#[derive(TryFromMultipart, Debug)]
+#[form_data(collect_errors)]
+struct Quux {
+    #[form_data(alias = "old_age")]
+    #[allow(dead_code)]
+    age: u8,
+}
+
+#[tokio::test]
+async fn test_alias_collect_errors() {
+    let mut form = Form::default();
+    form.add_text("old_age", "abc");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Quux>::from_request(request, &()).await.unwrap_err();
+
+    let TypedMultipartError::Multiple(errors) = error else { panic!("expected multiple errors") };
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].field_name, "age");
+    assert!(matches!(
+        &errors[0].error,
+        TypedMultipartError::WrongFieldType { field_name, .. } if field_name == "age"
+    ));
+}
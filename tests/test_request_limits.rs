@@ -0,0 +1,67 @@
+mod util;
+
+use axum::extract::FromRequest;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum_typed_multipart::{
+    TryFromMultipart, TypedMultipart, TypedMultipartConfig, TypedMultipartError,
+};
+use common_multipart_rfc7578::client::multipart::Form;
+use util::get_request_from_form;
+
+#[derive(TryFromMultipart, Debug)]
+struct Foo {
+    #[allow(dead_code)]
+    names: Vec<String>,
+}
+
+async fn get_result(
+    names: usize,
+    config: TypedMultipartConfig,
+) -> Result<Foo, TypedMultipartError> {
+    let mut form = Form::default();
+
+    for _ in 0..names {
+        form.add_text("names", "Potato!");
+    }
+
+    let mut request = get_request_from_form(form).await;
+    request.extensions_mut().insert(config);
+
+    TypedMultipart::<Foo>::from_request(request, &()).await.map(|data| data.0)
+}
+
+#[tokio::test]
+async fn test_request_limits_valid() {
+    let config =
+        TypedMultipartConfig::new().max_body_size(10_000).max_parts(3).max_part_header_size(100);
+
+    let data = get_result(3, config).await.unwrap();
+
+    assert_eq!(data.names.len(), 3);
+}
+
+#[tokio::test]
+async fn test_request_too_large() {
+    let error = get_result(3, TypedMultipartConfig::new().max_body_size(100)).await.unwrap_err();
+
+    assert!(matches!(error, TypedMultipartError::RequestTooLarge { limit_bytes: 100 }));
+    assert_eq!(error.into_response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn test_too_many_parts() {
+    let error = get_result(4, TypedMultipartConfig::new().max_parts(3)).await.unwrap_err();
+
+    assert!(matches!(error, TypedMultipartError::TooManyParts { limit: 3 }));
+    assert_eq!(error.into_response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn test_part_headers_too_large() {
+    let config = TypedMultipartConfig::new().max_part_header_size(10);
+    let error = get_result(1, config).await.unwrap_err();
+
+    assert!(matches!(error, TypedMultipartError::PartHeadersTooLarge { limit_bytes: 10 }));
+    assert_eq!(error.into_response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
@@ -0,0 +1,40 @@
+mod util;
+
+use axum::extract::FromRequest;
+use axum_typed_multipart::{TryFromMultipart, TypedMultipart};
+use common_multipart_rfc7578::client::multipart::Form;
+use util::get_request_from_form;
+
+#[derive(TryFromMultipart)]
+struct Item {
+    name: String,
+}
+
+/// The fields share the names of the variables used by the generated code,
+/// which must not shadow them.
+#[derive(TryFromMultipart)]
+#[form_data(collect_errors)]
+struct Foo {
+    path: String,
+    name: String,
+    state: Option<u8>,
+    #[form_data(nested)]
+    values: Vec<Item>,
+}
+
+#[tokio::test]
+async fn test_reserved_identifiers() {
+    let mut form = Form::default();
+    form.add_text("path", "/tmp/potato.txt");
+    form.add_text("name", "potato.txt");
+    form.add_text("state", "1");
+    form.add_text("values[0][name]", "Potato");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.path, "/tmp/potato.txt");
+    assert_eq!(data.name, "potato.txt");
+    assert_eq!(data.state, Some(1));
+    assert_eq!(data.values[0].name, "Potato");
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "sniff")]
+
+mod util;
+
+use axum::body::Bytes;
+use axum::extract::FromRequest;
+use axum_typed_multipart::{Sniffed, TryFromMultipart, TypedMultipart, TypedMultipartError};
+use common_multipart_rfc7578::client::multipart::Form;
+use std::io::Cursor;
+use util::get_request_from_form;
+
+/// Minimal PDF document header.
+const PDF: &[u8] = b"%PDF-1.7\n";
+
+#[derive(TryFromMultipart)]
+struct Foo {
+    document: Sniffed<Bytes>,
+    text: Sniffed<String>,
+}
+
+#[derive(TryFromMultipart)]
+struct Bar {
+    #[allow(dead_code)]
+    document: Sniffed<Bytes, true>,
+}
+
+fn get_form(mime: mime::Mime) -> Form<'static> {
+    let mut form = Form::default();
+    form.add_reader_file_with_mime("document", Cursor::new(PDF), "document.pdf", mime);
+    form
+}
+
+#[tokio::test]
+async fn test_sniffed() {
+    let mut form = get_form(mime::TEXT_PLAIN);
+    form.add_text("text", "Potato!");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.document.contents, PDF);
+    assert_eq!(data.document.detected_type.as_deref(), Some("application/pdf"));
+    assert_eq!(data.text.contents, "Potato!");
+    assert_eq!(data.text.detected_type, None);
+}
+
+#[tokio::test]
+async fn test_sniffed_strict() {
+    let request = get_request_from_form(get_form(mime::APPLICATION_PDF)).await;
+    assert!(TypedMultipart::<Bar>::from_request(request, &()).await.is_ok());
+
+    let request = get_request_from_form(get_form(mime::IMAGE_PNG)).await;
+    let error = TypedMultipart::<Bar>::from_request(request, &()).await.err().unwrap();
+
+    assert!(matches!(
+        error,
+        TypedMultipartError::ContentTypeMismatch { detected, .. } if detected == "application/pdf"
+    ));
+}
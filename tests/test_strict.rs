@@ -0,0 +1,38 @@
+mod util;
+
+use axum::extract::FromRequest;
+use axum_typed_multipart::{TryFromMultipart, TypedMultipart, TypedMultipartError};
+use common_multipart_rfc7578::client::multipart::Form;
+use util::get_request_from_form;
+
+#[derive(TryFromMultipart, Debug)]
+#[form_data(strict)]
+struct Foo {
+    name: String,
+}
+
+#[tokio::test]
+async fn test_strict() {
+    let mut form = Form::default();
+    form.add_text("name", "John");
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.name, "John");
+}
+
+#[tokio::test]
+async fn test_strict_unknown_field() {
+    let mut form = Form::default();
+    form.add_text("name", "John");
+    form.add_text("nmae", "Doe");
+
+    let request = get_request_from_form(form).await;
+    let error = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        TypedMultipartError::UnknownField { field_name } if field_name == "nmae"
+    ));
+}
@@ -1,7 +1,9 @@
 mod util;
 
 use axum::extract::FromRequest;
-use axum_typed_multipart::{TempFile, TryFromMultipart, TypedMultipart};
+use axum_typed_multipart::{
+    PersistOrCopyError, TempFile, TempFileConfig, TryFromMultipart, TypedMultipart,
+};
 use common_multipart_rfc7578::client::multipart::Form;
 use std::fs::read_to_string;
 use std::io::BufReader;
@@ -36,3 +38,113 @@ async fn test_temp_file() {
 
     assert_eq!(data, "Potato!");
 }
+
+#[tokio::test]
+async fn test_temp_file_large() {
+    let contents = "Potato!".repeat(100_000);
+    let mut form = Form::default();
+
+    form.add_reader_file_with_mime(
+        "file",
+        BufReader::new(contents.as_bytes()),
+        "potato.txt",
+        mime::TEXT_PLAIN,
+    );
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    let temp_dir = tempdir().unwrap();
+    let file_path = temp_dir.path().join("potato.txt");
+
+    data.file.persist(&file_path, false).await.unwrap();
+
+    assert_eq!(read_to_string(&file_path).unwrap(), contents);
+}
+
+#[tokio::test]
+async fn test_temp_file_config() {
+    let mut form = Form::default();
+
+    form.add_reader_file_with_mime(
+        "file",
+        BufReader::new("Potato!".as_bytes()),
+        "potato.txt",
+        mime::TEXT_PLAIN,
+    );
+
+    let temp_dir = tempdir().unwrap();
+    let config = TempFileConfig::new().dir(temp_dir.path()).prefix("upload-").suffix(".txt");
+
+    let mut request = get_request_from_form(form).await;
+    request.extensions_mut().insert(config);
+
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+    let path = data.file.path();
+    let file_name = path.file_name().unwrap().to_str().unwrap();
+
+    assert_eq!(path.parent().unwrap(), temp_dir.path());
+    assert!(file_name.starts_with("upload-"));
+    assert!(file_name.ends_with(".txt"));
+    assert_eq!(read_to_string(path).unwrap(), "Potato!");
+}
+
+#[tokio::test]
+async fn test_temp_file_persist_or_copy() {
+    let temp_dir = tempdir().unwrap();
+    let file_path = temp_dir.path().join("potato.txt");
+    std::fs::write(&file_path, "Tomato!").unwrap();
+
+    for replace in [false, true] {
+        let mut form = Form::default();
+
+        form.add_reader_file_with_mime(
+            "file",
+            BufReader::new("Potato!".as_bytes()),
+            "potato.txt",
+            mime::TEXT_PLAIN,
+        );
+
+        let request = get_request_from_form(form).await;
+        let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+        let result = data.file.persist_or_copy(&file_path, replace).await;
+
+        match replace {
+            false => {
+                let error = result.unwrap_err();
+                assert!(matches!(error, PersistOrCopyError::AlreadyExists { .. }));
+                assert_eq!(read_to_string(error.into_file().path()).unwrap(), "Potato!");
+            }
+            true => assert!(result.is_ok()),
+        }
+    }
+
+    assert_eq!(read_to_string(&file_path).unwrap(), "Potato!");
+}
+
+#[tokio::test]
+async fn test_temp_file_persist_or_copy_failure() {
+    let mut form = Form::default();
+
+    form.add_reader_file_with_mime(
+        "file",
+        BufReader::new("Potato!".as_bytes()),
+        "potato.txt",
+        mime::TEXT_PLAIN,
+    );
+
+    let request = get_request_from_form(form).await;
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    // Both the rename and the copy fail since the target directory is missing.
+    let temp_dir = tempdir().unwrap();
+    let missing_path = temp_dir.path().join("missing").join("potato.txt");
+    let error = data.file.persist_or_copy(&missing_path, false).await.unwrap_err();
+    assert!(matches!(error, PersistOrCopyError::Io { .. }));
+
+    // The data is not lost, so the operation can be retried.
+    let file_path = temp_dir.path().join("potato.txt");
+    error.into_file().persist_or_copy(&file_path, false).await.unwrap();
+
+    assert_eq!(read_to_string(&file_path).unwrap(), "Potato!");
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "checksum")]
+
+use axum::extract::FromRequest;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::Request;
+use axum_typed_multipart::{TryFromMultipart, TypedMultipart, TypedMultipartError, Verified};
+
+#[derive(TryFromMultipart)]
+struct Foo {
+    field: Verified<String>,
+}
+
+/// Request containing a single field with the supplied additional header.
+fn get_request_with_header(header: &str) -> Request<String> {
+    let body = format!(
+        "--BOUNDARY\r\n\
+        Content-Disposition: form-data; name=\"field\"\r\n\
+        {header}\r\n\r\n\
+        Potato!\r\n\
+        --BOUNDARY--\r\n"
+    );
+
+    Request::builder()
+        .uri("https://www.rust-lang.org/")
+        .method("POST")
+        .header(CONTENT_TYPE, "multipart/form-data; boundary=BOUNDARY")
+        .body(body)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_verified() {
+    let headers = [
+        "Content-MD5: wk5BnKgnh71srETXhr1eVQ==",
+        "Digest: SHA=IpQh6s6zbXMfJI1k0PC0tPJz2aw=, UNKNOWN=AAAA",
+        "Repr-Digest: sha-256=:v4LvpYSRJjz1w8iGb38g7BjSXywIlLMjGEp2Je1SkIQ=:",
+    ];
+
+    for header in headers {
+        let request = get_request_with_header(header);
+        let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+        assert_eq!(data.field.contents, "Potato!");
+        assert!(data.field.verified);
+    }
+}
+
+#[tokio::test]
+async fn test_verified_without_checksum() {
+    let request = get_request_with_header("X-Custom: 42");
+    let data = TypedMultipart::<Foo>::from_request(request, &()).await.unwrap().0;
+
+    assert_eq!(data.field.contents, "Potato!");
+    assert!(!data.field.verified);
+}
+
+#[tokio::test]
+async fn test_checksum_mismatch() {
+    let request = get_request_with_header("Content-MD5: AAAAAAAAAAAAAAAAAAAAAA==");
+    let error = TypedMultipart::<Foo>::from_request(request, &()).await.err().unwrap();
+
+    assert_eq!(error.to_string(), "field 'field' does not match the supplied MD5 checksum");
+    assert!(matches!(error, TypedMultipartError::ChecksumMismatch { .. }));
+}